@@ -1,9 +1,17 @@
 use crate::{
     actor::{
         actor_template::{
+            BossConfig,
+            HomingConfig,
+            LaserConfig,
             MissileConfig,
             NateroidConfig,
+            PlanetConfig,
+            SaucerConfig,
+            SaucerMissileConfig,
             SpaceshipConfig,
+            SpreadConfig,
+            TorpedoConfig,
         },
         get_scene_aabb,
         Aabb,
@@ -18,6 +26,7 @@ use crate::{
         toggle_active,
         GlobalAction,
     },
+    orientation::CameraOrientation,
     playfield::{
         ActorPortals,
         Boundary,
@@ -51,10 +60,23 @@ pub struct ActorSpawner;
 
 impl Plugin for ActorSpawner {
     fn build(&self, app: &mut App) {
-        app.register_type::<MissileConfig>()
+        app.register_type::<BossConfig>()
+            .register_type::<HomingConfig>()
+            .register_type::<LaserConfig>()
+            .register_type::<MissileConfig>()
             .register_type::<NateroidConfig>()
+            .register_type::<PlanetConfig>()
+            .register_type::<SaucerConfig>()
+            .register_type::<SaucerMissileConfig>()
             .register_type::<SpaceshipConfig>()
+            .register_type::<SpreadConfig>()
+            .register_type::<TorpedoConfig>()
             .add_systems(OnEnter(AssetsState::Loaded), initialize_actor_configs)
+            .add_systems(Update, follow_orientation_locks)
+            .add_plugins(
+                ResourceInspectorPlugin::<BossConfig>::default()
+                    .run_if(toggle_active(false, GlobalAction::BossInspector)),
+            )
             .add_plugins(
                 ResourceInspectorPlugin::<MissileConfig>::default()
                     .run_if(toggle_active(false, GlobalAction::MissileInspector)),
@@ -63,9 +85,34 @@ impl Plugin for ActorSpawner {
                 ResourceInspectorPlugin::<NateroidConfig>::default()
                     .run_if(toggle_active(false, GlobalAction::NateroidInspector)),
             )
+            .add_plugins(
+                ResourceInspectorPlugin::<PlanetConfig>::default()
+                    .run_if(toggle_active(false, GlobalAction::PlanetInspector)),
+            )
+            .add_plugins(
+                ResourceInspectorPlugin::<SaucerConfig>::default()
+                    .run_if(toggle_active(false, GlobalAction::SaucerInspector)),
+            )
             .add_plugins(
                 ResourceInspectorPlugin::<SpaceshipConfig>::default()
                     .run_if(toggle_active(false, GlobalAction::SpaceshipInspector)),
+            )
+            // the alternate weapons share one toggle
+            .add_plugins(
+                ResourceInspectorPlugin::<HomingConfig>::default()
+                    .run_if(toggle_active(false, GlobalAction::WeaponInspector)),
+            )
+            .add_plugins(
+                ResourceInspectorPlugin::<LaserConfig>::default()
+                    .run_if(toggle_active(false, GlobalAction::WeaponInspector)),
+            )
+            .add_plugins(
+                ResourceInspectorPlugin::<SpreadConfig>::default()
+                    .run_if(toggle_active(false, GlobalAction::WeaponInspector)),
+            )
+            .add_plugins(
+                ResourceInspectorPlugin::<TorpedoConfig>::default()
+                    .run_if(toggle_active(false, GlobalAction::WeaponInspector)),
             );
     }
 }
@@ -73,9 +120,25 @@ impl Plugin for ActorSpawner {
 #[derive(Reflect, Component, Clone, Debug)]
 pub struct Health(pub f32);
 
+impl Health {
+    /// returns true only for the blow that takes health from positive to zero
+    /// or below - anything hitting an already dead actor before it's despawned
+    /// doesn't count
+    pub fn take_damage(&mut self, damage: f32) -> bool {
+        let was_alive = self.0 > 0.0;
+        self.0 -= damage;
+        was_alive && self.0 <= 0.0
+    }
+}
+
 #[derive(Reflect, Component, Clone, Debug)]
 pub struct CollisionDamage(pub f32);
 
+/// the locked axes an actor was configured with - they only apply while we're
+/// flying in the plane. in 3d nothing is locked
+#[derive(Component, Clone, Copy, Debug)]
+pub struct PlanarLockedAxes(pub LockedAxes);
+
 #[derive(Reflect, Debug, Clone, PartialEq, Eq)]
 pub enum ColliderType {
     Ball,
@@ -89,12 +152,26 @@ pub enum SpawnPositionBehavior {
     ForwardFromParent { distance: f32 },
 }
 
+impl SpawnPositionBehavior {
+    // a template that stays in the plane gets as much depth as it has width
+    fn with_depth(&self) -> Self {
+        match self {
+            Self::RandomWithinBounds { scale_factor } if scale_factor.z == 0.0 => Self::RandomWithinBounds {
+                scale_factor: scale_factor.with_z(scale_factor.x.max(scale_factor.y)),
+            },
+            _ => self.clone(),
+        }
+    }
+}
+
+// depth - when it's false a random linvel stays in the plane
 #[derive(Reflect, Debug, Clone)]
 pub enum VelocityBehavior {
     Fixed(Vec3),
     Random {
         linvel: f32,
         angvel: f32,
+        depth:  bool,
     },
     RelativeToParent {
         base_velocity:           f32,
@@ -110,9 +187,12 @@ impl VelocityBehavior {
     ) -> Velocity {
         match self {
             VelocityBehavior::Fixed(velocity) => Velocity::linear(*velocity),
-            VelocityBehavior::Random { linvel, angvel } => Velocity {
-                linvel: random_vec3(-*linvel..*linvel, -*linvel..*linvel, 0.0..0.0),
-                angvel: random_vec3(-*angvel..*angvel, -*angvel..*angvel, -*angvel..*angvel),
+            VelocityBehavior::Random { linvel, angvel, depth } => {
+                let depth_linvel = if *depth { *linvel } else { 0.0 };
+                Velocity {
+                    linvel: random_vec3(-*linvel..*linvel, -*linvel..*linvel, -depth_linvel..depth_linvel),
+                    angvel: random_vec3(-*angvel..*angvel, -*angvel..*angvel, -*angvel..*angvel),
+                }
             },
             VelocityBehavior::RelativeToParent {
                 base_velocity,
@@ -199,6 +279,29 @@ impl Default for ActorConfig {
 }
 
 impl ActorConfig {
+    /// templates describe actors flying in the plane - in 3d, random spawn
+    /// positions and velocities reach into the boundary's depth as well
+    pub fn oriented(&self, orientation: &CameraOrientation) -> Self {
+        if !orientation.config.allow_3d {
+            return self.clone();
+        }
+
+        let velocity_behavior = match &self.velocity_behavior {
+            VelocityBehavior::Random { linvel, angvel, .. } => VelocityBehavior::Random {
+                linvel: *linvel,
+                angvel: *angvel,
+                depth:  true,
+            },
+            velocity_behavior => velocity_behavior.clone(),
+        };
+
+        Self {
+            spawn_position_behavior: self.spawn_position_behavior.with_depth(),
+            velocity_behavior,
+            ..self.clone()
+        }
+    }
+
     fn calculate_spawn_transform(
         &self,
         parent: Option<(&Transform, &Aabb)>,
@@ -264,9 +367,11 @@ pub struct ActorBundle {
     pub collider:         Collider,
     pub collision_damage: CollisionDamage,
     pub collision_groups: CollisionGroups,
+    pub external_force:   ExternalForce,
     pub gravity_scale:    GravityScale,
     pub health:           Health,
     pub locked_axes:      LockedAxes,
+    pub planar_locks:     PlanarLockedAxes,
     pub rigid_body:       RigidBody,
     pub restitution:      Restitution,
     pub mass_properties:  ColliderMassProperties,
@@ -303,9 +408,11 @@ impl ActorBundle {
             collider: config.collider.clone(),
             collision_damage: CollisionDamage(config.collision_damage),
             collision_groups: config.collision_groups,
+            external_force: ExternalForce::default(),
             gravity_scale: GravityScale(config.gravity_scale),
             health: Health(config.health),
             locked_axes: config.locked_axes,
+            planar_locks: PlanarLockedAxes(config.locked_axes),
             rigid_body: config.rigid_body,
             restitution: Restitution {
                 coefficient:  config.restitution,
@@ -347,7 +454,32 @@ impl ActorBundle {
     }
 }
 
-fn get_random_position_within_bounds(bounds: &Transform) -> Vec3 {
+// coming back out of 3d anything that drifted off the plane is put back on it
+// before its locks go back on - otherwise it'd be stuck off to the side
+fn follow_orientation_locks(
+    mut q_actors: Query<(Ref<PlanarLockedAxes>, &mut LockedAxes, &mut Transform, &mut Velocity)>,
+    orientation: Res<CameraOrientation>,
+) {
+    for (planar_locks, mut locked_axes, mut transform, mut velocity) in q_actors.iter_mut() {
+        if !orientation.is_changed() && !planar_locks.is_added() {
+            continue;
+        }
+
+        if orientation.config.allow_3d {
+            *locked_axes = LockedAxes::empty();
+            continue;
+        }
+
+        if planar_locks.0.contains(LockedAxes::TRANSLATION_LOCKED_Z) {
+            transform.translation.z = 0.0;
+            velocity.linvel.z = 0.0;
+        }
+
+        *locked_axes = planar_locks.0;
+    }
+}
+
+pub fn get_random_position_within_bounds(bounds: &Transform) -> Vec3 {
     let mut rng = rand::thread_rng();
     let half_scale = bounds.scale.abs() / 2.0; // Use absolute value to ensure positive scale
     let min = bounds.translation - half_scale;
@@ -379,11 +511,14 @@ fn get_random_rotation() -> Quat {
     )
 }
 
-#[derive(Component, Reflect, Copy, Clone, Debug, Default)]
+#[derive(Component, Reflect, Copy, Clone, Debug, Default, PartialEq, Eq)]
 pub enum ActorKind {
     #[default]
     Missile,
     Nateroid,
+    Planet,
+    Saucer,
+    SaucerMissile,
     Spaceship,
 }
 
@@ -392,6 +527,9 @@ impl fmt::Display for ActorKind {
         match self {
             ActorKind::Missile => write!(f, "Missile"),
             ActorKind::Nateroid => write!(f, "Nateroid"),
+            ActorKind::Planet => write!(f, "Planet"),
+            ActorKind::Saucer => write!(f, "Saucer"),
+            ActorKind::SaucerMissile => write!(f, "SaucerMissile"),
             ActorKind::Spaceship => write!(f, "Spaceship"),
         }
     }
@@ -403,13 +541,29 @@ fn initialize_actor_configs(
     scenes: Res<Assets<Scene>>,
     scene_assets: Res<SceneAssets>,
 ) {
+    let nateroid_defaults = NateroidConfig::default();
     let nateroid_config = initialize_actor_config(
-        NateroidConfig::default().0,
+        nateroid_defaults.actor,
+        &scenes,
+        &meshes,
+        &scene_assets.nateroid,
+    );
+    commands.insert_resource(NateroidConfig {
+        actor: nateroid_config,
+        ..nateroid_defaults
+    });
+
+    let boss_defaults = BossConfig::default();
+    let boss_config = initialize_actor_config(
+        boss_defaults.actor,
         &scenes,
         &meshes,
         &scene_assets.nateroid,
     );
-    commands.insert_resource(NateroidConfig(nateroid_config));
+    commands.insert_resource(BossConfig {
+        actor: boss_config,
+        ..boss_defaults
+    });
 
     let missile_config = initialize_actor_config(
         MissileConfig::default().0,
@@ -419,6 +573,38 @@ fn initialize_actor_configs(
     );
     commands.insert_resource(MissileConfig(missile_config));
 
+    let planet_defaults = PlanetConfig::default();
+    let planet_config = initialize_actor_config(
+        planet_defaults.actor,
+        &scenes,
+        &meshes,
+        &scene_assets.planet,
+    );
+    commands.insert_resource(PlanetConfig {
+        actor: planet_config,
+        ..planet_defaults
+    });
+
+    let saucer_defaults = SaucerConfig::default();
+    let saucer_config = initialize_actor_config(
+        saucer_defaults.actor,
+        &scenes,
+        &meshes,
+        &scene_assets.saucer,
+    );
+    commands.insert_resource(SaucerConfig {
+        actor: saucer_config,
+        ..saucer_defaults
+    });
+
+    let saucer_missile_config = initialize_actor_config(
+        SaucerMissileConfig::default().0,
+        &scenes,
+        &meshes,
+        &scene_assets.missile,
+    );
+    commands.insert_resource(SaucerMissileConfig(saucer_missile_config));
+
     let spaceship_config = initialize_actor_config(
         SpaceshipConfig::default().0,
         &scenes,
@@ -426,6 +612,50 @@ fn initialize_actor_configs(
         &scene_assets.spaceship,
     );
     commands.insert_resource(SpaceshipConfig(spaceship_config));
+
+    let homing_defaults = HomingConfig::default();
+    let homing_config = initialize_actor_config(
+        homing_defaults.actor,
+        &scenes,
+        &meshes,
+        &scene_assets.missile,
+    );
+    commands.insert_resource(HomingConfig {
+        actor: homing_config,
+        ..homing_defaults
+    });
+
+    let laser_defaults = LaserConfig::default();
+    let laser_config = initialize_actor_config(
+        laser_defaults.actor,
+        &scenes,
+        &meshes,
+        &scene_assets.missile,
+    );
+    commands.insert_resource(LaserConfig {
+        actor: laser_config,
+        ..laser_defaults
+    });
+
+    let spread_defaults = SpreadConfig::default();
+    let spread_config = initialize_actor_config(
+        spread_defaults.actor,
+        &scenes,
+        &meshes,
+        &scene_assets.missile,
+    );
+    commands.insert_resource(SpreadConfig {
+        actor: spread_config,
+        ..spread_defaults
+    });
+
+    let torpedo_config = initialize_actor_config(
+        TorpedoConfig::default().0,
+        &scenes,
+        &meshes,
+        &scene_assets.missile,
+    );
+    commands.insert_resource(TorpedoConfig(torpedo_config));
 }
 
 fn initialize_actor_config(
@@ -437,17 +667,7 @@ fn initialize_actor_config(
     let aabb = get_scene_aabb(scenes, meshes, scene_handle);
     let adjusted_aabb = aabb.scale(BLENDER_SCALE);
 
-    // Calculate the size based on the adjusted AABB
-    let size = adjusted_aabb.size();
-    let half_extents = adjusted_aabb.half_extents();
-
-    let collider = match config.collider_type {
-        ColliderType::Ball => {
-            let radius = size.length() / 3.;
-            Collider::ball(radius)
-        },
-        ColliderType::Cuboid => Collider::cuboid(half_extents.x, half_extents.y, half_extents.z),
-    };
+    let collider = build_collider(&adjusted_aabb, &config.collider_type);
 
     let spawn_timer = config
         .spawn_timer_seconds
@@ -460,6 +680,20 @@ fn initialize_actor_config(
     config
 }
 
+pub fn build_collider(aabb: &Aabb, collider_type: &ColliderType) -> Collider {
+    // Calculate the size based on the adjusted AABB
+    let size = aabb.size();
+    let half_extents = aabb.half_extents();
+
+    match collider_type {
+        ColliderType::Ball => {
+            let radius = size.length() / 3.;
+            Collider::ball(radius)
+        },
+        ColliderType::Cuboid => Collider::cuboid(half_extents.x, half_extents.y, half_extents.z),
+    }
+}
+
 pub fn random_vec3(range_x: Range<f32>, range_y: Range<f32>, range_z: Range<f32>) -> Vec3 {
     let mut rng = rand::thread_rng();
     let x = if range_x.start < range_x.end {
@@ -6,6 +6,7 @@
 /// can be thought of as an ActorConfig
 use crate::actor::{
     actor_spawner::{
+        build_collider,
         ActorConfig,
         ActorKind,
         SpawnPositionBehavior,
@@ -13,10 +14,19 @@ use crate::actor::{
     },
     ColliderType,
 };
-use bevy::prelude::*;
-use bevy_inspector_egui::InspectorOptions;
+use bevy::{
+    color::palettes::tailwind,
+    prelude::*,
+};
+use bevy_inspector_egui::{
+    inspector_options::std_options::NumberDisplay,
+    prelude::*,
+};
 use bevy_rapier3d::{
-    dynamics::LockedAxes,
+    dynamics::{
+        LockedAxes,
+        RigidBody,
+    },
     geometry::Group,
     prelude::CollisionGroups,
 };
@@ -24,19 +34,214 @@ use bevy_rapier3d::{
 pub const GROUP_SPACESHIP: Group = Group::GROUP_1;
 pub const GROUP_ASTEROID: Group = Group::GROUP_2;
 pub const GROUP_MISSILE: Group = Group::GROUP_3;
+pub const GROUP_SAUCER: Group = Group::GROUP_4;
+pub const GROUP_SAUCER_MISSILE: Group = Group::GROUP_5;
+pub const GROUP_PLANET: Group = Group::GROUP_6;
+
+// shared by every weapon the spaceship can fire
+fn player_weapon_collision_groups() -> CollisionGroups {
+    CollisionGroups::new(GROUP_MISSILE, GROUP_ASTEROID | GROUP_SAUCER | GROUP_PLANET)
+}
+
+// a homing missile only locks on to targets within lock_on_range that are
+// inside a cone lock_on_angle radians either side of where it's heading, and it
+// can only turn turn_rate radians per second to chase them
+#[derive(Resource, Reflect, InspectorOptions, Debug, Clone)]
+#[reflect(Resource, InspectorOptions)]
+pub struct HomingConfig {
+    pub actor:         ActorConfig,
+    #[inspector(min = 0.0, max = std::f32::consts::PI, display = NumberDisplay::Slider)]
+    pub lock_on_angle: f32,
+    #[inspector(min = 10.0, max = 500.0, display = NumberDisplay::Slider)]
+    pub lock_on_range: f32,
+    #[inspector(min = 0.0, max = 10.0, display = NumberDisplay::Slider)]
+    pub turn_rate:     f32,
+}
+
+// the laser never spawns its actor - it hits whatever is first along a ray out
+// to range and the beam is only drawn for beam_duration. the actor is still
+// where the laser's damage, collision groups and fire rate come from
+#[derive(Resource, Reflect, InspectorOptions, Debug, Clone)]
+#[reflect(Resource, InspectorOptions)]
+pub struct LaserConfig {
+    pub actor:         ActorConfig,
+    pub beam_color:    Color,
+    #[inspector(min = 0.01, max = 0.5, display = NumberDisplay::Slider)]
+    pub beam_duration: f32,
+    #[inspector(min = 10.0, max = 500.0, display = NumberDisplay::Slider)]
+    pub range:         f32,
+}
 
 #[derive(Resource, Reflect, InspectorOptions, Debug, Clone)]
 #[reflect(Resource)]
 pub struct MissileConfig(pub ActorConfig);
 
+// fragment_count is how many smaller nateroids a dead nateroid splits into
+// and fragment_depth is how many times that can recurse - 0 turns it off
+// each generation multiplies the previous generation's scalar, health and
+// collision damage by the matching fragment_*_factor
+#[derive(Resource, Reflect, InspectorOptions, Debug, Clone)]
+#[reflect(Resource, InspectorOptions)]
+pub struct NateroidConfig {
+    pub actor:                  ActorConfig,
+    #[inspector(min = 0, max = 8, display = NumberDisplay::Slider)]
+    pub fragment_count:         u32,
+    #[inspector(min = 0.0, max = 1.0, display = NumberDisplay::Slider)]
+    pub fragment_damage_factor: f32,
+    #[inspector(min = 0, max = 4, display = NumberDisplay::Slider)]
+    pub fragment_depth:         u32,
+    #[inspector(min = 0.0, max = 1.0, display = NumberDisplay::Slider)]
+    pub fragment_health_factor: f32,
+    #[inspector(min = 0.1, max = 1.0, display = NumberDisplay::Slider)]
+    pub fragment_scale_factor:  f32,
+    #[inspector(min = 0.0, max = 100.0, display = NumberDisplay::Slider)]
+    pub fragment_spread_speed:  f32,
+}
+
+// a boss phase starts once the boss is down to health_fraction of the health it
+// spawned with - phases are checked in order and the last one reached wins
+#[derive(Reflect, Debug, Clone)]
+pub struct BossPhase {
+    pub behavior:        BossBehavior,
+    pub health_fraction: f32,
+}
+
+#[derive(Reflect, Debug, Clone)]
+pub enum BossBehavior {
+    // spin up to angvel radians per second
+    Spin { angvel: f32 },
+    // throw off fragment_count first generation fragments every interval seconds
+    Shed { fragment_count: u32, interval: f32 },
+    // turn toward the spaceship at steering and go for it at speed
+    Charge { speed: f32, steering: f32 },
+}
+
+// every wave_interval'th wave brings a boss along with it - the boss is still a
+// nateroid so it counts toward clearing the wave and splits up when it dies
+#[derive(Resource, Reflect, InspectorOptions, Debug, Clone)]
+#[reflect(Resource, InspectorOptions)]
+pub struct BossConfig {
+    pub actor:         ActorConfig,
+    pub phases:        Vec<BossPhase>,
+    #[inspector(min = 1, max = 20, display = NumberDisplay::Slider)]
+    pub wave_interval: u32,
+}
+
+// where each planet sits and how hard it pulls - pull_strength is the
+// acceleration at the planet's center and it falls off to nothing at
+// pull_radius. scalar multiplies actor.scalar so planets can differ in size
+#[derive(Reflect, Debug, Clone)]
+pub struct PlanetPlacement {
+    pub position:      Vec3,
+    pub pull_radius:   f32,
+    pub pull_strength: f32,
+    pub scalar:        f32,
+}
+
+#[derive(Resource, Reflect, InspectorOptions, Debug, Clone)]
+#[reflect(Resource, InspectorOptions)]
+pub struct PlanetConfig {
+    pub actor:   ActorConfig,
+    pub planets: Vec<PlanetPlacement>,
+}
+
+// accuracy of 1.0 fires straight at the spaceship - lower values add up to
+// max_aim_error radians of random error to each shot
+// the saucer closes on the spaceship at max_speed until it's within
+// standoff_distance - steering is how quickly it turns toward where it wants to
+// go
+#[derive(Resource, Reflect, InspectorOptions, Debug, Clone)]
+#[reflect(Resource, InspectorOptions)]
+pub struct SaucerConfig {
+    pub actor:             ActorConfig,
+    #[inspector(min = 0.0, max = 1.0, display = NumberDisplay::Slider)]
+    pub accuracy:          f32,
+    #[inspector(min = 0.1, max = 10.0, display = NumberDisplay::Slider)]
+    pub fire_interval:     f32,
+    #[inspector(min = 0.0, max = std::f32::consts::FRAC_PI_2, display = NumberDisplay::Slider)]
+    pub max_aim_error:     f32,
+    #[inspector(min = 0.0, max = 150.0, display = NumberDisplay::Slider)]
+    pub max_speed:         f32,
+    #[inspector(min = 0.0, max = 200.0, display = NumberDisplay::Slider)]
+    pub standoff_distance: f32,
+    #[inspector(min = 0.1, max = 10.0, display = NumberDisplay::Slider)]
+    pub steering:          f32,
+}
+
 #[derive(Resource, Reflect, InspectorOptions, Debug, Clone)]
 #[reflect(Resource)]
-pub struct NateroidConfig(pub ActorConfig);
+pub struct SaucerMissileConfig(pub ActorConfig);
 
 #[derive(Resource, Reflect, InspectorOptions, Debug, Clone)]
 #[reflect(Resource)]
 pub struct SpaceshipConfig(pub ActorConfig);
 
+// each shot fans projectile_count missiles out evenly across spread_angle
+// radians centered on the spaceship's heading
+#[derive(Resource, Reflect, InspectorOptions, Debug, Clone)]
+#[reflect(Resource, InspectorOptions)]
+pub struct SpreadConfig {
+    pub actor:            ActorConfig,
+    #[inspector(min = 1, max = 12, display = NumberDisplay::Slider)]
+    pub projectile_count: u32,
+    #[inspector(min = 0.0, max = std::f32::consts::PI, display = NumberDisplay::Slider)]
+    pub spread_angle:     f32,
+}
+
+#[derive(Resource, Reflect, InspectorOptions, Debug, Clone)]
+#[reflect(Resource)]
+pub struct TorpedoConfig(pub ActorConfig);
+
+impl Default for HomingConfig {
+    fn default() -> Self {
+        let actor = ActorConfig {
+            actor_kind: ActorKind::Missile,
+            collision_damage: 40.,
+            collision_groups: player_weapon_collision_groups(),
+            health: 1.,
+            mass: 0.1,
+            // #todo: #handle3d
+            rotation: Some(Quat::from_rotation_x(std::f32::consts::FRAC_PI_2)),
+            spawn_position_behavior: SpawnPositionBehavior::ForwardFromParent { distance: 0.5 },
+            scalar: 2.5,
+            spawn_timer_seconds: Some(0.5),
+            velocity_behavior: VelocityBehavior::RelativeToParent {
+                base_velocity:           60.0,
+                inherit_parent_velocity: true,
+            },
+            ..default()
+        };
+
+        Self {
+            actor,
+            lock_on_angle: std::f32::consts::FRAC_PI_4,
+            lock_on_range: 120.,
+            turn_rate: 3.,
+        }
+    }
+}
+
+// every player weapon is an ActorKind::Missile so kills are scored the same no
+// matter which weapon landed them - spawn_timer_seconds is the fire rate
+impl Default for LaserConfig {
+    fn default() -> Self {
+        let actor = ActorConfig {
+            actor_kind: ActorKind::Missile,
+            collision_damage: 15.,
+            collision_groups: player_weapon_collision_groups(),
+            spawn_timer_seconds: Some(1.0 / 30.0),
+            ..default()
+        };
+
+        Self {
+            actor,
+            beam_color: Color::from(tailwind::RED_500),
+            beam_duration: 0.05,
+            range: 150.,
+        }
+    }
+}
+
 // todo: #rustquestion - why isn't rustfmt lining these up? it does if i get of
 // default
 impl Default for MissileConfig {
@@ -44,7 +249,7 @@ impl Default for MissileConfig {
         Self(ActorConfig {
             actor_kind: ActorKind::Missile,
             collision_damage: 50.,
-            collision_groups: CollisionGroups::new(GROUP_MISSILE, GROUP_ASTEROID),
+            collision_groups: player_weapon_collision_groups(),
             health: 1.,
             mass: 0.1,
             // #todo: #handle3d
@@ -63,7 +268,7 @@ impl Default for MissileConfig {
 
 impl Default for NateroidConfig {
     fn default() -> Self {
-        Self(ActorConfig {
+        let actor = ActorConfig {
             actor_kind: ActorKind::Nateroid,
             collider_type: ColliderType::Cuboid,
             collision_damage: 10.,
@@ -76,8 +281,180 @@ impl Default for NateroidConfig {
             velocity_behavior: VelocityBehavior::Random {
                 linvel: 30.0,
                 angvel: 4.0,
+                depth:  false,
+            },
+            ..default()
+        };
+
+        Self {
+            actor,
+            fragment_count: 3,
+            fragment_damage_factor: 0.5,
+            fragment_depth: 2,
+            fragment_health_factor: 0.5,
+            fragment_scale_factor: 0.5,
+            fragment_spread_speed: 20.,
+        }
+    }
+}
+
+impl Default for BossConfig {
+    fn default() -> Self {
+        let actor = ActorConfig {
+            collision_damage: 100.,
+            health: 3000.,
+            mass: 20.0,
+            scalar: 4.,
+            velocity_behavior: VelocityBehavior::Random {
+                linvel: 10.0,
+                angvel: 1.0,
+                depth:  false,
+            },
+            ..NateroidConfig::default().actor
+        };
+
+        Self {
+            actor,
+            phases: vec![
+                BossPhase {
+                    behavior:        BossBehavior::Spin { angvel: 8. },
+                    health_fraction: 0.75,
+                },
+                BossPhase {
+                    behavior:        BossBehavior::Shed {
+                        fragment_count: 2,
+                        interval:       2.,
+                    },
+                    health_fraction: 0.5,
+                },
+                BossPhase {
+                    behavior:        BossBehavior::Charge {
+                        speed:    45.,
+                        steering: 1.,
+                    },
+                    health_fraction: 0.25,
+                },
+            ],
+            wave_interval: 5,
+        }
+    }
+}
+
+impl NateroidConfig {
+    /// the ActorConfig for a fragment `generation` splits away from the
+    /// original - generation 0 is the original nateroid
+    ///
+    /// the collider is rebuilt from the aabb scaled down to the fragment's size
+    /// so spawn_fragments has to stop rapier from scaling it a second time by
+    /// the transform's scale
+    pub fn fragment_actor_config(&self, generation: u32) -> ActorConfig {
+        let exponent = generation as i32;
+        let scalar = self.actor.scalar * self.fragment_scale_factor.powi(exponent);
+
+        ActorConfig {
+            collider: build_collider(&self.actor.aabb.scale(scalar), &self.actor.collider_type),
+            collision_damage: self.actor.collision_damage * self.fragment_damage_factor.powi(exponent),
+            health: self.actor.health * self.fragment_health_factor.powi(exponent),
+            scalar,
+            spawn_timer: None,
+            ..self.actor.clone()
+        }
+    }
+}
+
+// planets never move and can't be destroyed - anything that touches one takes
+// collision_damage, which is enough to finish off a full health spaceship
+impl Default for PlanetConfig {
+    fn default() -> Self {
+        let actor = ActorConfig {
+            actor_kind: ActorKind::Planet,
+            collider_type: ColliderType::Ball,
+            collision_damage: 1000.,
+            collision_groups: CollisionGroups::new(
+                GROUP_PLANET,
+                GROUP_SPACESHIP | GROUP_ASTEROID | GROUP_MISSILE | GROUP_SAUCER | GROUP_SAUCER_MISSILE,
+            ),
+            health: f32::INFINITY,
+            restitution: 0.5,
+            rigid_body: RigidBody::Fixed,
+            ..default()
+        };
+
+        Self {
+            actor,
+            planets: vec![
+                PlanetPlacement {
+                    position:      Vec3::new(-65.0, 25.0, 0.0),
+                    pull_radius:   60.,
+                    pull_strength: 60.,
+                    scalar:        1.,
+                },
+                PlanetPlacement {
+                    position:      Vec3::new(70.0, 15.0, 0.0),
+                    pull_radius:   40.,
+                    pull_strength: 40.,
+                    scalar:        0.6,
+                },
+            ],
+        }
+    }
+}
+
+impl Default for SaucerConfig {
+    fn default() -> Self {
+        let actor = ActorConfig {
+            actor_kind: ActorKind::Saucer,
+            collider_type: ColliderType::Ball,
+            collision_damage: 50.,
+            collision_groups: CollisionGroups::new(
+                GROUP_SAUCER,
+                GROUP_SPACESHIP | GROUP_MISSILE | GROUP_ASTEROID | GROUP_PLANET,
+            ),
+            health: 150.,
+            mass: 5.0,
+            restitution: 0.5,
+            scalar: 1.5,
+            spawn_position_behavior: SpawnPositionBehavior::RandomWithinBounds {
+                scale_factor: Vec3::new(0.5, 0.5, 0.0),
+            },
+            spawn_timer_seconds: Some(15.),
+            velocity_behavior: VelocityBehavior::Fixed(Vec3::ZERO),
+            ..default()
+        };
+
+        Self {
+            actor,
+            accuracy: 0.6,
+            fire_interval: 1.5,
+            max_aim_error: std::f32::consts::FRAC_PI_4,
+            max_speed: 35.,
+            standoff_distance: 40.,
+            steering: 1.5,
+        }
+    }
+}
+
+// saucer missiles can hit the spaceship and get blocked by nateroids but they
+// never hit another saucer
+impl Default for SaucerMissileConfig {
+    fn default() -> Self {
+        Self(ActorConfig {
+            actor_kind: ActorKind::SaucerMissile,
+            collision_damage: 50.,
+            collision_groups: CollisionGroups::new(
+                GROUP_SAUCER_MISSILE,
+                GROUP_SPACESHIP | GROUP_ASTEROID | GROUP_PLANET,
+            ),
+            health: 1.,
+            mass: 0.1,
+            // #todo: #handle3d
+            rotation: Some(Quat::from_rotation_x(std::f32::consts::FRAC_PI_2)),
+            spawn_position_behavior: SpawnPositionBehavior::ForwardFromParent { distance: 0.5 },
+            scalar: 2.5,
+            velocity_behavior: VelocityBehavior::RelativeToParent {
+                base_velocity:           60.0,
+                inherit_parent_velocity: true,
             },
-            spawn_timer_seconds: Some(2.),
             ..default()
         })
     }
@@ -88,7 +465,10 @@ impl Default for SpaceshipConfig {
         Self(ActorConfig {
             actor_kind: ActorKind::Spaceship,
             collision_damage: 50.,
-            collision_groups: CollisionGroups::new(GROUP_SPACESHIP, GROUP_ASTEROID),
+            collision_groups: CollisionGroups::new(
+                GROUP_SPACESHIP,
+                GROUP_ASTEROID | GROUP_SAUCER | GROUP_SAUCER_MISSILE | GROUP_PLANET,
+            ),
             health: 500.,
             mass: 10.0,
             locked_axes: LockedAxes::ROTATION_LOCKED_X
@@ -104,3 +484,54 @@ impl Default for SpaceshipConfig {
         })
     }
 }
+
+impl Default for SpreadConfig {
+    fn default() -> Self {
+        let actor = ActorConfig {
+            actor_kind: ActorKind::Missile,
+            collision_damage: 25.,
+            collision_groups: player_weapon_collision_groups(),
+            health: 1.,
+            mass: 0.1,
+            // #todo: #handle3d
+            rotation: Some(Quat::from_rotation_x(std::f32::consts::FRAC_PI_2)),
+            spawn_position_behavior: SpawnPositionBehavior::ForwardFromParent { distance: 0.5 },
+            scalar: 2.,
+            spawn_timer_seconds: Some(0.25),
+            velocity_behavior: VelocityBehavior::RelativeToParent {
+                base_velocity:           85.0,
+                inherit_parent_velocity: true,
+            },
+            ..default()
+        };
+
+        Self {
+            actor,
+            projectile_count: 5,
+            spread_angle: 0.5,
+        }
+    }
+}
+
+// slow and heavy - it takes a while to get there but one hit is usually enough
+impl Default for TorpedoConfig {
+    fn default() -> Self {
+        Self(ActorConfig {
+            actor_kind: ActorKind::Missile,
+            collision_damage: 300.,
+            collision_groups: player_weapon_collision_groups(),
+            health: 1.,
+            mass: 2.,
+            // #todo: #handle3d
+            rotation: Some(Quat::from_rotation_x(std::f32::consts::FRAC_PI_2)),
+            spawn_position_behavior: SpawnPositionBehavior::ForwardFromParent { distance: 1.0 },
+            scalar: 6.,
+            spawn_timer_seconds: Some(1.0),
+            velocity_behavior: VelocityBehavior::RelativeToParent {
+                base_velocity:           35.0,
+                inherit_parent_velocity: true,
+            },
+            ..default()
+        })
+    }
+}
@@ -0,0 +1,242 @@
+use crate::{
+    actor::{
+        actor_spawner::spawn_actor,
+        actor_template::{
+            BossBehavior,
+            BossConfig,
+            NateroidConfig,
+        },
+        nateroid::{
+            spawn_fragments,
+            Nateroid,
+        },
+        spaceship::Spaceship,
+        Aabb,
+        Health,
+    },
+    camera::RenderLayer,
+    despawn::despawn,
+    orientation::CameraOrientation,
+    playfield::Boundary,
+    schedule::InGameSet,
+    state::PlayingGame,
+};
+use bevy::{
+    color::palettes::tailwind,
+    prelude::*,
+    render::view::RenderLayers,
+};
+use bevy_rapier3d::prelude::Velocity;
+
+const HEALTH_BAR_HEIGHT: f32 = 12.;
+const HEALTH_BAR_WIDTH: f32 = 40.;
+
+pub struct BossPlugin;
+
+impl Plugin for BossPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            (advance_boss_phase, run_boss_phase, update_boss_health_bar)
+                .chain()
+                .in_set(InGameSet::EntityUpdates),
+        )
+        // the boss itself goes with everything else that has health but the
+        // bar has none
+        .add_systems(OnExit(PlayingGame), despawn_boss_health_bar);
+    }
+}
+
+// phase is None until health drops far enough to reach the first phase in
+// BossConfig::phases
+#[derive(Component, Debug)]
+pub struct Boss {
+    max_health: f32,
+    phase:      Option<usize>,
+    shed_timer: Option<Timer>,
+}
+
+#[derive(Component)]
+struct BossHealthBar;
+
+#[derive(Component)]
+struct BossHealthBarFill;
+
+// the wave director decides when - a boss is a nateroid that happens to be huge
+pub fn spawn_boss(
+    commands: &mut Commands,
+    config: &BossConfig,
+    boundary: Res<Boundary>,
+    orientation: &CameraOrientation,
+    now: f32,
+) {
+    if !config.actor.spawnable {
+        return;
+    }
+
+    println!("boss incoming");
+
+    spawn_actor(commands, &config.actor.oriented(orientation), Some(boundary), None)
+        .insert(Nateroid {
+            generation: 0,
+            spawned_at: now,
+        })
+        .insert(Boss {
+            max_health: config.actor.health,
+            phase:      None,
+            shed_timer: None,
+        });
+}
+
+fn advance_boss_phase(mut q_bosses: Query<(&Health, &mut Boss)>, config: Res<BossConfig>) {
+    for (health, mut boss) in q_bosses.iter_mut() {
+        let health_fraction = health.0 / boss.max_health.max(f32::EPSILON);
+
+        let phase = config
+            .phases
+            .iter()
+            .rposition(|phase| health_fraction <= phase.health_fraction);
+
+        if phase == boss.phase {
+            continue;
+        }
+
+        boss.phase = phase;
+        boss.shed_timer = phase.and_then(|index| match config.phases[index].behavior {
+            BossBehavior::Shed { interval, .. } => Some(Timer::from_seconds(interval, TimerMode::Repeating)),
+            _ => None,
+        });
+
+        if let Some(index) = phase {
+            println!("boss phase {}: {:?}", index + 1, config.phases[index].behavior);
+        }
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+fn run_boss_phase(
+    mut commands: Commands,
+    mut q_bosses: Query<(&Transform, &mut Velocity, &Aabb, &mut Boss)>,
+    q_spaceship: Query<&Transform, With<Spaceship>>,
+    boundary: Res<Boundary>,
+    config: Res<BossConfig>,
+    nateroid_config: Res<NateroidConfig>,
+    orientation: Res<CameraOrientation>,
+    time: Res<Time>,
+) {
+    for (transform, mut velocity, aabb, mut boss) in q_bosses.iter_mut() {
+        let Some(phase) = boss.phase.and_then(|index| config.phases.get(index)) else {
+            continue;
+        };
+
+        match phase.behavior {
+            BossBehavior::Spin { angvel } => {
+                velocity.angvel = velocity.angvel.normalize_or(Vec3::Z) * angvel;
+            },
+            BossBehavior::Shed { fragment_count, .. } => {
+                let Some(shed_timer) = boss.shed_timer.as_mut() else {
+                    continue;
+                };
+                shed_timer.tick(time.delta());
+
+                if shed_timer.just_finished() {
+                    // start the fragments outside of the boss rather than inside it
+                    let clearance = aabb.max_dimension() * transform.scale.max_element() / 2.0;
+
+                    spawn_fragments(
+                        &mut commands,
+                        &nateroid_config,
+                        1,
+                        fragment_count,
+                        (transform, &*velocity),
+                        clearance,
+                        &orientation,
+                        time.elapsed_secs(),
+                    );
+                }
+            },
+            BossBehavior::Charge { speed, steering } => {
+                let spaceships = q_spaceship.iter().map(|spaceship| spaceship.translation);
+                let Some(to_spaceship) = boundary.nearest_wrapped_delta(transform.translation, spaceships)
+                else {
+                    continue;
+                };
+
+                let desired_velocity = to_spaceship.normalize_or_zero() * speed;
+
+                let steering = (steering * time.delta_secs()).min(1.0);
+                velocity.linvel = velocity.linvel.lerp(desired_velocity, steering);
+
+                //todo: #handl3d
+                if !orientation.config.allow_3d {
+                    velocity.linvel.z = 0.0;
+                }
+            },
+        }
+    }
+}
+
+// only one bar no matter how many bosses there are - it tracks whichever one
+// is closest to dying
+fn update_boss_health_bar(
+    mut commands: Commands,
+    q_bosses: Query<(&Health, &Boss)>,
+    q_health_bar: Query<Entity, With<BossHealthBar>>,
+    mut q_fill: Query<&mut Node, With<BossHealthBarFill>>,
+) {
+    let health_fraction = q_bosses
+        .iter()
+        .map(|(health, boss)| (health.0 / boss.max_health.max(f32::EPSILON)).clamp(0.0, 1.0))
+        .reduce(f32::min);
+
+    let Some(health_fraction) = health_fraction else {
+        for entity in q_health_bar.iter() {
+            despawn(&mut commands, entity);
+        }
+        return;
+    };
+
+    if q_health_bar.is_empty() {
+        spawn_boss_health_bar(&mut commands);
+        return;
+    }
+
+    for mut node in q_fill.iter_mut() {
+        node.width = Val::Percent(health_fraction * 100.);
+    }
+}
+
+fn spawn_boss_health_bar(commands: &mut Commands) {
+    commands
+        .spawn((
+            BossHealthBar,
+            Node {
+                position_type: PositionType::Absolute,
+                top: Val::Px(16.),
+                left: Val::Percent((100. - HEALTH_BAR_WIDTH) / 2.),
+                width: Val::Percent(HEALTH_BAR_WIDTH),
+                height: Val::Px(HEALTH_BAR_HEIGHT),
+                ..default()
+            },
+            BackgroundColor(Color::from(tailwind::GRAY_800)),
+            RenderLayers::from_layers(RenderLayer::Game.layers()),
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                BossHealthBarFill,
+                Node {
+                    width: Val::Percent(100.),
+                    height: Val::Percent(100.),
+                    ..default()
+                },
+                BackgroundColor(Color::from(tailwind::RED_600)),
+                RenderLayers::from_layers(RenderLayer::Game.layers()),
+            ));
+        });
+}
+
+fn despawn_boss_health_bar(mut commands: Commands, q_health_bar: Query<Entity, With<BossHealthBar>>) {
+    for entity in q_health_bar.iter() {
+        despawn(&mut commands, entity);
+    }
+}
@@ -1,9 +1,26 @@
-use bevy::prelude::*;
-use bevy_rapier3d::prelude::CollisionEvent;
+use bevy::{
+    ecs::entity::EntityHashSet,
+    prelude::*,
+};
+use bevy_rapier3d::prelude::{
+    CollisionEvent,
+    ReadDefaultRapierContext,
+};
 
 use crate::{
     actor::{
-        actor_spawner::CollisionDamage,
+        actor_spawner::{
+            ActorKind,
+            CollisionDamage,
+        },
+        player::{
+            owning_player,
+            Owner,
+            Player,
+        },
+        shield::Shield,
+        spaceship::Invulnerable,
+        weapon::ShotHit,
         Health,
     },
     schedule::InGameSet,
@@ -13,34 +30,136 @@ pub struct CollisionDetectionPlugin;
 
 impl Plugin for CollisionDetectionPlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(
-            FixedUpdate,
-            handle_collision_events.in_set(InGameSet::CollisionDetection),
-        );
+        app.add_event::<DamageEvent>()
+            .add_event::<KillEvent>()
+            .add_systems(
+                FixedUpdate,
+                handle_collision_events.in_set(InGameSet::CollisionDetection),
+            );
     }
 }
 
+/// sent the moment a collision takes an actor's health from positive to zero
+/// or below - the victim is still around until despawn_dead_entities gets to
+/// it, so anything that wants to react to the kill (scoring for one) can still
+/// query the victim as long as it runs in InGameSet::DespawnEntities
+///
+/// killer_player is whoever gets the credit - None when the killer doesn't
+/// belong to a player (a nateroid ramming a spaceship, say)
+#[derive(Event, Debug, Clone, Copy)]
+pub struct KillEvent {
+    pub victim:        Entity,
+    pub victim_kind:   ActorKind,
+    pub killer_kind:   ActorKind,
+    pub killer_player: Option<Player>,
+}
+
+/// sent whenever damage actually lands on an actor's health - after the shield
+/// has taken its share. projectiles using themselves up don't count. position
+/// is where the hit happened in world space and killing_blow is set on the
+/// same hit that sends the KillEvent
+#[derive(Event, Debug, Clone, Copy)]
+pub struct DamageEvent {
+    pub amount:       f32,
+    pub position:     Vec3,
+    pub killing_blow: bool,
+}
+
+/// everything that can take damage - invulnerable actors (e.g., a freshly
+/// respawned spaceship) simply aren't in it
+pub type DamageableQuery<'w, 's> =
+    Query<'w, 's, (&'static mut Health, Option<&'static mut Shield>), Without<Invulnerable>>;
+
+/// a raised shield soaks up what it can before anything reaches health -
+/// returns the damage that got through and whether it was the killing blow,
+/// or None when `entity` can't be damaged at all
+pub fn deal_damage(
+    damageable_query: &mut DamageableQuery,
+    entity: Entity,
+    damage: f32,
+) -> Option<(f32, bool)> {
+    let (mut health, shield) = damageable_query.get_mut(entity).ok()?;
+    let dealt = shield.map_or(damage, |mut shield| shield.absorb(damage));
+
+    // only the blow that crosses zero counts as the kill
+    Some((dealt, health.take_damage(dealt)))
+}
+
+/// only nateroids, saucers and spaceships can be scored - planets soak up
+/// shots forever and other projectiles are just in the way
+pub fn can_be_scored(kind: ActorKind) -> bool {
+    matches!(kind, ActorKind::Nateroid | ActorKind::Saucer | ActorKind::Spaceship)
+}
+
+/// whether a player's projectile touching `victim_kind` lands a shot - a
+/// projectile can touch several colliders in the same step but it's still only
+/// the one shot, so `landed_shots` remembers the ones that already have
+pub fn lands_shot(landed_shots: &mut EntityHashSet, projectile: Entity, victim_kind: ActorKind) -> bool {
+    can_be_scored(victim_kind) && landed_shots.insert(projectile)
+}
+
+#[allow(clippy::too_many_arguments)]
 fn handle_collision_events(
     mut collision_events: EventReader<CollisionEvent>,
-    mut health_query: Query<&mut Health>,
+    mut damageable_query: DamageableQuery,
     name_query: Query<&Name>,
     collision_damage_query: Query<&CollisionDamage>,
+    kind_query: Query<&ActorKind>,
+    owner_query: Query<(Option<&Player>, Option<&Owner>)>,
+    transform_query: Query<&Transform>,
+    mut damage_events: EventWriter<DamageEvent>,
+    mut kill_events: EventWriter<KillEvent>,
+    mut shots_hit: EventWriter<ShotHit>,
+    mut landed_shots: Local<EntityHashSet>,
+    rapier_context: ReadDefaultRapierContext,
 ) {
+    let rapier_context = rapier_context.single();
+
+    // projectiles that are gone can't land again
+    landed_shots.retain(|&projectile| owner_query.contains(projectile));
+
     for &collision_event in collision_events.read() {
         if let CollisionEvent::Started(entity1, entity2, ..) = collision_event {
             if let Ok(name1) = name_query.get(entity1) {
                 if let Ok(name2) = name_query.get(entity2) {
+                    // sensors don't generate contacts so fall back to halfway
+                    // between the two
+                    let contact = rapier_context
+                        .contact_pair(entity1, entity2)
+                        .and_then(|pair| {
+                            let (manifold, _) = pair.find_deepest_contact()?;
+                            manifold.solver_contact(0).map(|solver_contact| solver_contact.point())
+                        })
+                        .or_else(|| {
+                            let [transform1, transform2] = transform_query.get_many([entity1, entity2]).ok()?;
+                            Some(transform1.translation.lerp(transform2.translation, 0.5))
+                        });
+
                     apply_collision_damage(
-                        &mut health_query,
+                        &mut damageable_query,
                         &collision_damage_query,
+                        &kind_query,
+                        &owner_query,
+                        &mut damage_events,
+                        &mut kill_events,
+                        &mut shots_hit,
+                        &mut landed_shots,
+                        contact,
                         entity1,
                         name1,
                         entity2,
                         name2,
                     );
                     apply_collision_damage(
-                        &mut health_query,
+                        &mut damageable_query,
                         &collision_damage_query,
+                        &kind_query,
+                        &owner_query,
+                        &mut damage_events,
+                        &mut kill_events,
+                        &mut shots_hit,
+                        &mut landed_shots,
+                        contact,
                         entity2,
                         name2,
                         entity1,
@@ -52,17 +171,95 @@ fn handle_collision_events(
     }
 }
 
+// the shield and invulnerability are handled by deal_damage - the laser goes
+// through the same thing so neither can be shot around
+#[allow(clippy::too_many_arguments)]
 fn apply_collision_damage(
-    health_query: &mut Query<&mut Health>,
+    damageable_query: &mut DamageableQuery,
     collision_damage_query: &Query<&CollisionDamage>,
+    kind_query: &Query<&ActorKind>,
+    owner_query: &Query<(Option<&Player>, Option<&Owner>)>,
+    damage_events: &mut EventWriter<DamageEvent>,
+    kill_events: &mut EventWriter<KillEvent>,
+    shots_hit: &mut EventWriter<ShotHit>,
+    landed_shots: &mut EntityHashSet,
+    contact: Option<Vec3>,
     applying_entity: Entity,
     _applying_entity_name: &Name,
     receiving_entity: Entity,
     _receiving_entity_name: &Name,
 ) {
-    if let Ok(mut health) = health_query.get_mut(receiving_entity) {
-        if let Ok(collision_damage) = collision_damage_query.get(applying_entity) {
-            health.0 -= collision_damage.0;
+    if let Ok(collision_damage) = collision_damage_query.get(applying_entity) {
+        if let Some((damage, killing_blow)) =
+            deal_damage(damageable_query, receiving_entity, collision_damage.0)
+        {
+            // a player's projectile landing - their spaceship ramming something
+            // doesn't count as a shot
+            if matches!(owner_query.get(applying_entity), Ok((_, Some(_))))
+                && kind_query
+                    .get(receiving_entity)
+                    .is_ok_and(|&victim_kind| lands_shot(landed_shots, applying_entity, victim_kind))
+            {
+                shots_hit.send(ShotHit);
+            }
+
+            // a projectile takes damage from whatever it hits too - that's just
+            // how it gets used up so showing it would double up every hit
+            let victim_is_projectile = matches!(
+                kind_query.get(receiving_entity),
+                Ok(ActorKind::Missile | ActorKind::SaucerMissile)
+            );
+
+            if let Some(position) = contact.filter(|_| damage > 0.0 && !victim_is_projectile) {
+                damage_events.send(DamageEvent {
+                    amount: damage,
+                    position,
+                    killing_blow,
+                });
+            }
+
+            if killing_blow {
+                if let (Ok(&victim_kind), Ok(&killer_kind)) =
+                    (kind_query.get(receiving_entity), kind_query.get(applying_entity))
+                {
+                    kill_events.send(KillEvent {
+                        victim: receiving_entity,
+                        victim_kind,
+                        killer_kind,
+                        killer_player: owning_player(owner_query, applying_entity),
+                    });
+                }
+            }
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hits_never_outnumber_shots_fired() {
+        let mut landed_shots = EntityHashSet::default();
+        let shots_fired = [Entity::from_raw(1), Entity::from_raw(2)];
+
+        // each shot touches two nateroids and a saucer in the same step
+        let touched = [ActorKind::Nateroid, ActorKind::Nateroid, ActorKind::Saucer];
+        let hits = shots_fired
+            .iter()
+            .flat_map(|&shot| touched.map(|kind| (shot, kind)))
+            .filter(|&(shot, kind)| lands_shot(&mut landed_shots, shot, kind))
+            .count();
+
+        assert!(hits <= shots_fired.len());
+    }
+
+    #[test]
+    fn only_targets_that_can_be_scored_land_a_shot() {
+        let mut landed_shots = EntityHashSet::default();
+
+        assert!(!lands_shot(&mut landed_shots, Entity::from_raw(1), ActorKind::Planet));
+        assert!(!lands_shot(&mut landed_shots, Entity::from_raw(1), ActorKind::SaucerMissile));
+        assert!(lands_shot(&mut landed_shots, Entity::from_raw(1), ActorKind::Nateroid));
+    }
+}
@@ -0,0 +1,220 @@
+use crate::{
+    actor::{
+        actor_spawner::get_random_position_within_bounds,
+        nateroid::Nateroid,
+        planet::GravityWell,
+        saucer::Saucer,
+        spaceship::{
+            Invulnerable,
+            Spaceship,
+        },
+        spaceship_control::SpaceshipControl,
+        Health,
+    },
+    global_input::{
+        toggle_active,
+        GlobalAction,
+    },
+    orientation::CameraOrientation,
+    playfield::{
+        Boundary,
+        TransientPortal,
+    },
+    schedule::InGameSet,
+};
+use bevy::{
+    color::palettes::tailwind,
+    prelude::*,
+};
+use bevy_inspector_egui::{
+    inspector_options::std_options::NumberDisplay,
+    prelude::*,
+    quick::ResourceInspectorPlugin,
+};
+use bevy_rapier3d::prelude::Velocity;
+use leafwing_input_manager::prelude::*;
+use rand::Rng;
+
+pub struct HyperspacePlugin;
+
+impl Plugin for HyperspacePlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<HyperspaceConfig>()
+            .init_resource::<HyperspaceConfig>()
+            .add_plugins(
+                ResourceInspectorPlugin::<HyperspaceConfig>::default()
+                    .run_if(toggle_active(false, GlobalAction::HyperspaceInspector)),
+            )
+            .add_systems(
+                Update,
+                (jump_to_hyperspace, cool_down_hyperspace)
+                    .chain()
+                    .in_set(InGameSet::UserInput),
+            );
+    }
+}
+
+// failure_chance is the odds that the jump goes wrong and destroys the ship -
+// an invulnerable ship always makes it through
+//
+// safe_distance - we try search_attempts random spots (kept within
+// arrival_scale_factor of the boundary so we don't land on a wall) looking for
+// one that's at least this far from any threat - if none are, we take the one
+// that's furthest away
+#[derive(Resource, Reflect, InspectorOptions, Debug, Clone)]
+#[reflect(Resource, InspectorOptions)]
+pub struct HyperspaceConfig {
+    #[inspector(min = 0.1, max = 1.0, display = NumberDisplay::Slider)]
+    pub arrival_scale_factor: f32,
+    #[inspector(min = 0.0, max = 30.0, display = NumberDisplay::Slider)]
+    pub cooldown_seconds:     f32,
+    #[inspector(min = 0.0, max = 1.0, display = NumberDisplay::Slider)]
+    pub failure_chance:       f32,
+    pub portal_color:         Color,
+    #[inspector(min = 0.1, max = 5.0, display = NumberDisplay::Slider)]
+    pub portal_duration:      f32,
+    #[inspector(min = 1.0, max = 50.0, display = NumberDisplay::Slider)]
+    pub portal_radius:        f32,
+    #[inspector(min = 0.0, max = 200.0, display = NumberDisplay::Slider)]
+    pub safe_distance:        f32,
+    #[inspector(min = 1, max = 100, display = NumberDisplay::Slider)]
+    pub search_attempts:      u32,
+}
+
+impl Default for HyperspaceConfig {
+    fn default() -> Self {
+        Self {
+            arrival_scale_factor: 0.9,
+            cooldown_seconds:     3.,
+            failure_chance:       0.1,
+            portal_color:         Color::from(tailwind::PURPLE_500),
+            portal_duration:      0.75,
+            portal_radius:        12.,
+            safe_distance:        40.,
+            search_attempts:      20,
+        }
+    }
+}
+
+#[derive(Component, Debug)]
+pub struct HyperspaceCooldown(Timer);
+
+#[allow(clippy::type_complexity)]
+fn jump_to_hyperspace(
+    mut commands: Commands,
+    mut q_spaceship: Query<
+        (
+            Entity,
+            &ActionState<SpaceshipControl>,
+            &mut Transform,
+            &mut Velocity,
+            &mut Health,
+            Has<Invulnerable>,
+        ),
+        (With<Spaceship>, Without<HyperspaceCooldown>),
+    >,
+    q_threats: Query<
+        &Transform,
+        (
+            Or<(With<Nateroid>, With<Saucer>, With<GravityWell>)>,
+            Without<Spaceship>,
+        ),
+    >,
+    boundary: Res<Boundary>,
+    config: Res<HyperspaceConfig>,
+    orientation: Res<CameraOrientation>,
+) {
+    for (entity, controls, mut transform, mut velocity, mut health, invulnerable) in q_spaceship.iter_mut() {
+        if !controls.just_pressed(&SpaceshipControl::Hyperspace) {
+            continue;
+        }
+
+        commands.entity(entity).insert(HyperspaceCooldown(Timer::from_seconds(
+            config.cooldown_seconds,
+            TimerMode::Once,
+        )));
+
+        commands.spawn(TransientPortal::new(
+            transform.translation,
+            config.portal_radius,
+            config.portal_duration,
+            config.portal_color,
+        ));
+
+        if !invulnerable && rand::thread_rng().gen::<f32>() < config.failure_chance {
+            println!("hyperspace malfunction - spaceship lost");
+            health.0 = 0.0;
+            continue;
+        }
+
+        let threats: Vec<Vec3> = q_threats.iter().map(|threat| threat.translation).collect();
+        let arrival = find_safe_position(&boundary, &config, orientation.config.allow_3d, &threats);
+
+        transform.translation = arrival;
+        *velocity = Velocity::zero();
+
+        commands.spawn(TransientPortal::new(
+            arrival,
+            config.portal_radius,
+            config.portal_duration,
+            config.portal_color,
+        ));
+    }
+}
+
+fn find_safe_position(
+    boundary: &Boundary,
+    config: &HyperspaceConfig,
+    allow_3d: bool,
+    threats: &[Vec3],
+) -> Vec3 {
+    let mut scale_factor = Vec3::splat(config.arrival_scale_factor);
+
+    //todo: #handl3d
+    if !allow_3d {
+        scale_factor.z = 0.0;
+    }
+
+    let bounds = Transform {
+        translation: boundary.transform.translation,
+        scale: boundary.transform.scale * scale_factor,
+        ..default()
+    };
+
+    let mut best_position = bounds.translation;
+    let mut best_distance = f32::MIN;
+
+    for _ in 0..config.search_attempts.max(1) {
+        let candidate = get_random_position_within_bounds(&bounds);
+
+        let nearest_threat = threats
+            .iter()
+            .map(|&threat| boundary.wrapped_delta(candidate, threat).length())
+            .fold(f32::MAX, f32::min);
+
+        if nearest_threat >= config.safe_distance {
+            return candidate;
+        }
+
+        if nearest_threat > best_distance {
+            best_position = candidate;
+            best_distance = nearest_threat;
+        }
+    }
+
+    best_position
+}
+
+fn cool_down_hyperspace(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut query: Query<(Entity, &mut HyperspaceCooldown)>,
+) {
+    for (entity, mut cooldown) in query.iter_mut() {
+        cooldown.0.tick(time.delta());
+
+        if cooldown.0.finished() {
+            commands.entity(entity).remove::<HyperspaceCooldown>();
+        }
+    }
+}
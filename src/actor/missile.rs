@@ -1,34 +1,31 @@
 use bevy::prelude::*;
-use bevy_rapier3d::prelude::*;
+use bevy_rapier3d::prelude::Velocity;
 
 use crate::{
     playfield::Boundary,
     schedule::InGameSet,
+    state::IsPaused,
 };
 
 use crate::actor::{
-    aabb::Aabb,
-    actor_spawner::ActorConfig,
-    actor_template::MissileConfig,
-    spaceship::{
-        ContinuousFire,
-        Spaceship,
-    },
+    actor_template::HomingConfig,
+    nateroid::Nateroid,
+    saucer::Saucer,
     Teleporter,
 };
 
-use crate::actor::{
-    actor_spawner::spawn_actor,
-    spaceship_control::SpaceshipControl,
-};
-use leafwing_input_manager::prelude::*;
-
 pub struct MissilePlugin;
 
 impl Plugin for MissilePlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(Update, fire_missile.in_set(InGameSet::UserInput))
-            .add_systems(Update, missile_movement.in_set(InGameSet::EntityUpdates));
+        // firing lives in weapon.rs - every projectile weapon spawns a Missile
+        app.add_systems(Update, missile_movement.in_set(InGameSet::EntityUpdates))
+            // steering changes velocity so keep it in step with the physics - and
+            // FixedUpdate isn't covered by InGameSet's pause condition
+            .add_systems(
+                FixedUpdate,
+                steer_homing_missiles.run_if(in_state(IsPaused::NotPaused)),
+            );
     }
 }
 
@@ -45,7 +42,7 @@ pub struct Missile {
 }
 
 impl Missile {
-    fn new(total_distance: f32) -> Self {
+    pub fn new(total_distance: f32) -> Self {
         Missile {
             // velocity,
             total_distance,
@@ -57,69 +54,10 @@ impl Missile {
     }
 }
 
-/// Logic to handle whether we're in continuous fire mode or just regular fire
-/// mode if continuous we want to make sure that enough time has passed and that
-/// we're holding down the fire button
-fn should_fire(
-    continuous_fire: Option<&ContinuousFire>,
-    missile_config: &mut ActorConfig,
-    time: Res<Time>,
-    q_input_map: Query<&ActionState<SpaceshipControl>>,
-) -> bool {
-    if !missile_config.spawnable {
-        return false;
-    }
-
-    let action_state = q_input_map.single();
-
-    if continuous_fire.is_some() {
-        // We know the timer exists, so we can safely unwrap it
-        let timer = missile_config.spawn_timer.as_mut().expect(
-            "configure missile spawn timer here: impl Default for
-InitialEnsembleConfig",
-        );
-        timer.tick(time.delta());
-        if !timer.just_finished() {
-            return false;
-        }
-        action_state.pressed(&SpaceshipControl::Fire)
-    } else {
-        action_state.just_pressed(&SpaceshipControl::Fire)
-    }
-}
-
-// todo: #bevyquestion - in an object oriented world i think of attaching fire
-// as a method to                       the spaceship - but there's a lot of
-// missile logic so i have it setup in missile                       so should i
-// have a simple fire method in method in spaceship that in turn calls this
-//                       fn or is having it here fine?
-fn fire_missile(
-    mut commands: Commands,
-    q_input_map: Query<&ActionState<SpaceshipControl>>,
-    q_spaceship: Query<(&Transform, &Velocity, &Aabb, Option<&ContinuousFire>), With<Spaceship>>,
-    boundary_config: Res<Boundary>,
-    mut missile_config: ResMut<MissileConfig>,
-    time: Res<Time>,
-) {
-    let Ok((spaceship_transform, spaceship_velocity, aabb, continuous_fire)) = q_spaceship.get_single()
-    else {
-        return;
-    };
-
-    if !should_fire(continuous_fire, &mut missile_config.0, time, q_input_map) {
-        return;
-    }
-
-    let missile = Missile::new(boundary_config.max_missile_distance());
-
-    spawn_actor(
-        &mut commands,
-        &missile_config.0,
-        None,
-        Some((spaceship_transform, spaceship_velocity, aabb)),
-    )
-    .insert(missile);
-}
+/// marks a Missile that steers toward the nearest target in front of it - see
+/// HomingConfig
+#[derive(Component, Debug)]
+pub struct Homing;
 
 /// we update missile movement so that it can be despawned after it has traveled
 /// its total distance
@@ -149,3 +87,47 @@ fn missile_movement(mut query: Query<(&Transform, &mut Missile, &Teleporter)>) {
         missile.last_position = Some(current_position);
     }
 }
+
+// targets are searched the short way around the boundary wrap so a missile
+// about to teleport will go after something just on the other side. the speed
+// never changes, only the heading - and the model is turned to match
+#[allow(clippy::type_complexity)]
+fn steer_homing_missiles(
+    mut q_missiles: Query<(&mut Transform, &mut Velocity), (With<Homing>, With<Missile>)>,
+    q_targets: Query<&Transform, (Or<(With<Nateroid>, With<Saucer>)>, Without<Missile>)>,
+    boundary: Res<Boundary>,
+    config: Res<HomingConfig>,
+    time: Res<Time>,
+) {
+    let max_turn = config.turn_rate * time.delta_secs();
+
+    for (mut transform, mut velocity) in q_missiles.iter_mut() {
+        let Some(heading) = velocity.linvel.try_normalize() else {
+            continue;
+        };
+
+        let nearest_target = q_targets
+            .iter()
+            .map(|target| boundary.wrapped_delta(transform.translation, target.translation))
+            .filter(|delta| {
+                delta.length() <= config.lock_on_range
+                    && heading.angle_between(*delta) <= config.lock_on_angle
+            })
+            .min_by(|a, b| a.length().total_cmp(&b.length()));
+
+        let Some(desired) = nearest_target.and_then(Vec3::try_normalize) else {
+            continue;
+        };
+
+        let angle = heading.angle_between(desired);
+        if angle <= f32::EPSILON {
+            continue;
+        }
+
+        let full_turn = Quat::from_rotation_arc(heading, desired);
+        let turn = Quat::IDENTITY.slerp(full_turn, (max_turn / angle).min(1.0));
+
+        velocity.linvel = turn * velocity.linvel;
+        transform.rotation = turn * transform.rotation;
+    }
+}
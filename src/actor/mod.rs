@@ -1,22 +1,42 @@
 mod aabb;
 mod actor_spawner;
 mod actor_template;
+mod boss;
 mod collision_detection;
+mod hyperspace;
 pub mod missile;
-mod nateroid;
+pub mod nateroid;
+mod pickup;
+mod planet;
+pub mod player;
+mod saucer;
+mod shield;
 mod spaceship;
 mod spaceship_control;
 mod teleport;
+mod versus;
+mod wave;
+pub mod weapon;
 
 use crate::actor::{
     aabb::AabbPlugin,
     actor_spawner::ActorSpawner,
+    boss::BossPlugin,
     collision_detection::CollisionDetectionPlugin,
+    hyperspace::HyperspacePlugin,
     missile::MissilePlugin,
     nateroid::NateroidPlugin,
+    pickup::PickupPlugin,
+    planet::PlanetPlugin,
+    player::PlayerPlugin,
+    saucer::SaucerPlugin,
+    shield::ShieldPlugin,
     spaceship::SpaceshipPlugin,
     spaceship_control::SpaceshipControlPlugin,
     teleport::TeleportPlugin,
+    versus::VersusPlugin,
+    wave::WavePlugin,
+    weapon::WeaponPlugin,
 };
 pub use crate::actor::{
     aabb::{
@@ -24,10 +44,29 @@ pub use crate::actor::{
         Aabb,
     },
     actor_spawner::{
+        ActorKind,
         ColliderType,
         Health,
     },
+    actor_template::SpaceshipConfig,
+    boss::Boss,
+    collision_detection::{
+        DamageEvent,
+        KillEvent,
+    },
+    spaceship::{
+        ContinuousFire,
+        Lives,
+        Spaceship,
+    },
+    spaceship_control::{
+        ControlScheme,
+        FlightModel,
+        SpaceshipControl,
+        SpaceshipControlConfig,
+    },
     teleport::Teleporter,
+    wave::Wave,
 };
 
 use bevy::prelude::*;
@@ -38,11 +77,21 @@ impl Plugin for ActorPlugin {
     fn build(&self, app: &mut App) {
         app.add_plugins(AabbPlugin)
             .add_plugins(ActorSpawner)
+            .add_plugins(BossPlugin)
             .add_plugins(CollisionDetectionPlugin)
+            .add_plugins(HyperspacePlugin)
             .add_plugins(MissilePlugin)
             .add_plugins(NateroidPlugin)
+            .add_plugins(PickupPlugin)
+            .add_plugins(PlanetPlugin)
+            .add_plugins(PlayerPlugin)
+            .add_plugins(SaucerPlugin)
+            .add_plugins(ShieldPlugin)
             .add_plugins(SpaceshipPlugin)
             .add_plugins(SpaceshipControlPlugin)
-            .add_plugins(TeleportPlugin);
+            .add_plugins(TeleportPlugin)
+            .add_plugins(VersusPlugin)
+            .add_plugins(WavePlugin)
+            .add_plugins(WeaponPlugin);
     }
 }
@@ -1,49 +1,149 @@
 use crate::{
     actor::{
-        actor_spawner::spawn_actor,
+        actor_spawner::{
+            spawn_actor,
+            SpawnPositionBehavior,
+        },
         actor_template::NateroidConfig,
+        Health,
     },
+    orientation::CameraOrientation,
     playfield::Boundary,
     schedule::InGameSet,
 };
-
-use crate::global_input::{
-    toggle_active,
-    GlobalAction,
-};
 use bevy::prelude::*;
+use bevy_rapier3d::prelude::{
+    ColliderScale,
+    Velocity,
+};
+use rand::Rng;
 
 pub struct NateroidPlugin;
 
 impl Plugin for NateroidPlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(
-            Update,
-            spawn_nateroid
-                .in_set(InGameSet::EntityUpdates)
-                .run_if(toggle_active(true, GlobalAction::SuppressNateroids)),
-        );
+        // dead nateroids are still around until the DespawnEntities flush
+        // so this is our last chance to split them up
+        app.add_systems(Update, fragment_nateroids.in_set(InGameSet::DespawnEntities));
     }
 }
 
-fn spawn_nateroid(
-    mut commands: Commands,
-    mut config: ResMut<NateroidConfig>,
+// spawned_at is the elapsed time when the nateroid came into the world so
+// scoring can tell how long it survived
+// generation is 0 for a nateroid spawned on its own and goes up by one each
+// time a nateroid splits
+#[derive(Component, Debug, Clone, Copy)]
+pub struct Nateroid {
+    pub generation: u32,
+    pub spawned_at: f32,
+}
+
+impl Nateroid {
+    pub fn age(&self, now: f32) -> f32 { (now - self.spawned_at).max(0.0) }
+}
+
+// the wave director decides when - this just puts a new nateroid somewhere
+// random within the boundary
+pub fn spawn_nateroid(
+    commands: &mut Commands,
+    config: &NateroidConfig,
     boundary: Res<Boundary>,
-    time: Res<Time>,
+    orientation: &CameraOrientation,
+    now: f32,
 ) {
-    let nateroid_config = &mut config.0;
-
-    if !nateroid_config.spawnable {
+    if !config.actor.spawnable {
         return;
     }
 
-    let spawn_timer = nateroid_config.spawn_timer.as_mut().unwrap();
-    spawn_timer.tick(time.delta());
+    spawn_actor(commands, &config.actor.oriented(orientation), Some(boundary), None).insert(Nateroid {
+        generation: 0,
+        spawned_at: now,
+    });
+}
+
+fn fragment_nateroids(
+    mut commands: Commands,
+    config: Res<NateroidConfig>,
+    orientation: Res<CameraOrientation>,
+    time: Res<Time>,
+    q_nateroids: Query<(&Health, &Nateroid, &Transform, &Velocity)>,
+) {
+    for (health, nateroid, transform, velocity) in q_nateroids.iter() {
+        if health.0 > 0.0 || nateroid.generation >= config.fragment_depth || config.fragment_count == 0 {
+            continue;
+        }
+
+        spawn_fragments(
+            &mut commands,
+            &config,
+            nateroid.generation + 1,
+            config.fragment_count,
+            (transform, velocity),
+            0.0,
+            &orientation,
+            time.elapsed_secs(),
+        );
+    }
+}
 
-    if !spawn_timer.just_finished() {
+/// spreads `count` fragments of the given generation evenly around the parent.
+/// clearance is added to how far out they start for parents that are bigger
+/// than a nateroid of the previous generation would be
+#[allow(clippy::too_many_arguments)]
+pub fn spawn_fragments(
+    commands: &mut Commands,
+    config: &NateroidConfig,
+    generation: u32,
+    count: u32,
+    parent: (&Transform, &Velocity),
+    clearance: f32,
+    orientation: &CameraOrientation,
+    now: f32,
+) {
+    if count == 0 {
         return;
     }
 
-    spawn_actor(&mut commands, nateroid_config, Some(boundary), None);
+    let (transform, velocity) = parent;
+    let mut rng = rand::thread_rng();
+    let fragment_config = config.fragment_actor_config(generation).oriented(orientation);
+
+    // push the fragments out far enough that they don't start out overlapping
+    // one another - a fragment's world size is its aabb times its scalar
+    let offset = clearance + fragment_config.aabb.max_dimension() * fragment_config.scalar;
+
+    // spread evenly around the parent with a little jitter so it doesn't look
+    // too mechanical
+    let angle_step = std::f32::consts::TAU / count as f32;
+    let start_angle = rng.gen_range(0.0..std::f32::consts::TAU);
+
+    for i in 0..count {
+        let angle = start_angle + angle_step * i as f32 + rng.gen_range(-0.2_f32..0.2) * angle_step;
+        // in 3d the ring of fragments is tipped a random amount out of the plane
+        let depth = if orientation.config.allow_3d {
+            rng.gen_range(-1.0_f32..1.0)
+        } else {
+            0.0
+        };
+        let direction = Vec3::new(angle.cos(), angle.sin(), depth).normalize();
+
+        let fragment_velocity = Velocity {
+            linvel: velocity.linvel + direction * config.fragment_spread_speed,
+            angvel: velocity.angvel,
+        };
+
+        let mut spawn_config = fragment_config.clone();
+        spawn_config.spawn_position_behavior =
+            SpawnPositionBehavior::Fixed(transform.translation + direction * offset);
+        spawn_config.rotation = Some(transform.rotation);
+
+        // the collider is already built at the fragment's size
+        spawn_actor(commands, &spawn_config, None, None)
+            .insert(ColliderScale::Absolute(Vec3::ONE))
+            .insert(fragment_velocity)
+            .insert(Nateroid {
+                generation,
+                spawned_at: now,
+            });
+    }
 }
@@ -0,0 +1,173 @@
+use crate::{
+    actor::{
+        spaceship::Spaceship,
+        weapon::{
+            Weapon,
+            WeaponKind,
+            WeaponLimitsConfig,
+        },
+        ActorKind,
+        KillEvent,
+    },
+    despawn::despawn,
+    global_input::{
+        toggle_active,
+        GlobalAction,
+    },
+    orientation::CameraOrientation,
+    schedule::InGameSet,
+    state::PlayingGame,
+};
+use bevy::{
+    color::palettes::tailwind,
+    prelude::*,
+};
+use bevy_inspector_egui::{
+    inspector_options::std_options::NumberDisplay,
+    prelude::*,
+    quick::ResourceInspectorPlugin,
+};
+use rand::Rng;
+use strum::IntoEnumIterator;
+
+pub struct PickupPlugin;
+
+impl Plugin for PickupPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<PickupConfig>()
+            .init_resource::<PickupConfig>()
+            // shares the toggle with the weapon configs - pickups only matter
+            // to weapons with ammo
+            .add_plugins(
+                ResourceInspectorPlugin::<PickupConfig>::default()
+                    .run_if(toggle_active(false, GlobalAction::WeaponInspector)),
+            )
+            .add_systems(OnExit(PlayingGame), despawn_pickups)
+            // the nateroid is still around until the DespawnEntities flush so
+            // we can still find out where to drop the pickup
+            .add_systems(Update, drop_pickups.in_set(InGameSet::DespawnEntities))
+            .add_systems(
+                Update,
+                (collect_pickups, expire_pickups, draw_pickups)
+                    .chain()
+                    .in_set(InGameSet::EntityUpdates),
+            );
+    }
+}
+
+// a nateroid shot down by a player drops an ammo pickup drop_chance of the time
+// - only while at least one weapon is using ammo. it hangs around for
+// lifetime_seconds and any spaceship that comes within radius collects it
+#[derive(Resource, Reflect, InspectorOptions, Debug, Clone)]
+#[reflect(Resource, InspectorOptions)]
+pub struct PickupConfig {
+    pub color:            Color,
+    #[inspector(min = 0.0, max = 1.0, display = NumberDisplay::Slider)]
+    pub drop_chance:      f32,
+    #[inspector(min = 1.0, max = 60.0, display = NumberDisplay::Slider)]
+    pub lifetime_seconds: f32,
+    #[inspector(min = 0.5, max = 20.0, display = NumberDisplay::Slider)]
+    pub radius:           f32,
+}
+
+impl Default for PickupConfig {
+    fn default() -> Self {
+        Self {
+            color:            Color::from(tailwind::AMBER_400),
+            drop_chance:      0.25,
+            lifetime_seconds: 15.,
+            radius:           4.,
+        }
+    }
+}
+
+#[derive(Component, Debug)]
+pub struct AmmoPickup {
+    timer: Timer,
+}
+
+fn drop_pickups(
+    mut commands: Commands,
+    mut kill_events: EventReader<KillEvent>,
+    q_transform: Query<&Transform>,
+    config: Res<PickupConfig>,
+    limits_config: Res<WeaponLimitsConfig>,
+) {
+    let ammo_in_use = WeaponKind::iter().any(|kind| limits_config.limits(kind).ammo_enabled);
+    let mut rng = rand::thread_rng();
+
+    for kill in kill_events.read() {
+        if !ammo_in_use || kill.victim_kind != ActorKind::Nateroid || kill.killer_player.is_none() {
+            continue;
+        }
+
+        let Ok(transform) = q_transform.get(kill.victim) else {
+            continue;
+        };
+
+        if rng.gen::<f32>() >= config.drop_chance {
+            continue;
+        }
+
+        commands.spawn((
+            AmmoPickup {
+                timer: Timer::from_seconds(config.lifetime_seconds, TimerMode::Once),
+            },
+            Transform::from_translation(transform.translation),
+            Name::new("AmmoPickup"),
+        ));
+    }
+}
+
+fn collect_pickups(
+    mut commands: Commands,
+    q_pickups: Query<(Entity, &Transform), With<AmmoPickup>>,
+    mut q_spaceships: Query<(&Transform, &mut Weapon), With<Spaceship>>,
+    config: Res<PickupConfig>,
+    limits_config: Res<WeaponLimitsConfig>,
+) {
+    for (entity, pickup_transform) in q_pickups.iter() {
+        let collector = q_spaceships.iter_mut().find(|(spaceship_transform, _)| {
+            spaceship_transform.translation.distance(pickup_transform.translation) <= config.radius
+        });
+
+        if let Some((_, mut weapon)) = collector {
+            weapon.refill(&limits_config);
+            despawn(&mut commands, entity);
+        }
+    }
+}
+
+fn expire_pickups(mut commands: Commands, mut q_pickups: Query<(Entity, &mut AmmoPickup)>, time: Res<Time>) {
+    for (entity, mut pickup) in q_pickups.iter_mut() {
+        pickup.timer.tick(time.delta());
+
+        if pickup.timer.finished() {
+            despawn(&mut commands, entity);
+        }
+    }
+}
+
+// drawn flat in the plane the camera looks down on, same as the player markers
+fn draw_pickups(
+    q_pickups: Query<&Transform, With<AmmoPickup>>,
+    config: Res<PickupConfig>,
+    orientation: Res<CameraOrientation>,
+    mut gizmos: Gizmos,
+) {
+    let normal = Dir3::new(orientation.config.axis_profundus).unwrap_or(Dir3::Z);
+    let rotation = Quat::from_rotation_arc(Vec3::Z, normal.as_vec3());
+
+    for transform in q_pickups.iter() {
+        let isometry = Isometry3d::new(transform.translation, rotation);
+
+        gizmos.circle(isometry, config.radius, config.color);
+        gizmos.circle(isometry, config.radius / 2.0, config.color);
+    }
+}
+
+fn despawn_pickups(mut commands: Commands, q_pickups: Query<Entity, With<AmmoPickup>>) {
+    for entity in q_pickups.iter() {
+        despawn(&mut commands, entity);
+    }
+}
@@ -0,0 +1,103 @@
+use crate::{
+    actor::{
+        actor_spawner::{
+            spawn_actor,
+            ActorConfig,
+            SpawnPositionBehavior,
+        },
+        actor_template::PlanetConfig,
+        missile::Missile,
+        nateroid::Nateroid,
+        spaceship::Spaceship,
+    },
+    playfield::Boundary,
+    state::{
+        IsPaused,
+        PlayingGame,
+    },
+};
+use bevy::prelude::*;
+use bevy_rapier3d::prelude::{
+    ColliderMassProperties,
+    ExternalForce,
+};
+
+pub struct PlanetPlugin;
+
+impl Plugin for PlanetPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(OnEnter(PlayingGame), spawn_planets)
+            // forces are picked up by rapier on its next step - FixedUpdate isn't
+            // covered by InGameSet's pause condition so check it ourselves
+            .add_systems(
+                FixedUpdate,
+                apply_gravity_wells.run_if(in_state(IsPaused::NotPaused)),
+            );
+    }
+}
+
+#[derive(Component, Debug, Clone, Copy)]
+pub struct GravityWell {
+    pub pull_radius:   f32,
+    pub pull_strength: f32,
+}
+
+impl GravityWell {
+    /// acceleration toward the well for something `distance` away - full
+    /// strength at the center easing off to nothing at pull_radius
+    fn acceleration(&self, distance: f32) -> f32 {
+        if distance >= self.pull_radius {
+            return 0.0;
+        }
+
+        let falloff = 1.0 - distance / self.pull_radius.max(f32::EPSILON);
+        self.pull_strength * falloff * falloff
+    }
+}
+
+fn spawn_planets(mut commands: Commands, config: Res<PlanetConfig>) {
+    if !config.actor.spawnable {
+        return;
+    }
+
+    for placement in config.planets.iter() {
+        let planet_config = ActorConfig {
+            scalar: config.actor.scalar * placement.scalar,
+            spawn_position_behavior: SpawnPositionBehavior::Fixed(placement.position),
+            ..config.actor.clone()
+        };
+
+        spawn_actor(&mut commands, &planet_config, None, None).insert(GravityWell {
+            pull_radius:   placement.pull_radius,
+            pull_strength: placement.pull_strength,
+        });
+    }
+}
+
+// the pull is measured the short way around the boundary wrap so a ship just
+// across the edge from a planet still feels it. force is scaled by mass so
+// everything falls the same way no matter how heavy it is
+#[allow(clippy::type_complexity)]
+fn apply_gravity_wells(
+    q_wells: Query<(&Transform, &GravityWell)>,
+    mut q_bodies: Query<
+        (&Transform, &ColliderMassProperties, &mut ExternalForce),
+        (Or<(With<Spaceship>, With<Nateroid>, With<Missile>)>, Without<GravityWell>),
+    >,
+    boundary: Res<Boundary>,
+) {
+    for (transform, mass_properties, mut external_force) in q_bodies.iter_mut() {
+        let mass = match mass_properties {
+            ColliderMassProperties::Mass(mass) => *mass,
+            _ => 1.0,
+        };
+
+        external_force.force = q_wells
+            .iter()
+            .map(|(well_transform, well)| {
+                let to_well = boundary.wrapped_delta(transform.translation, well_transform.translation);
+                to_well.normalize_or_zero() * well.acceleration(to_well.length()) * mass
+            })
+            .sum();
+    }
+}
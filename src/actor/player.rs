@@ -0,0 +1,179 @@
+use crate::{
+    actor::{
+        spaceship::Spaceship,
+        spaceship_control::{
+            ControlScheme,
+            SpaceshipControl,
+        },
+        Aabb,
+    },
+    global_input::{
+        toggle_active,
+        GlobalAction,
+    },
+    orientation::CameraOrientation,
+    state::PlayingGame,
+};
+use bevy::{
+    color::palettes::tailwind,
+    prelude::*,
+};
+use bevy_inspector_egui::{
+    inspector_options::std_options::NumberDisplay,
+    prelude::*,
+    quick::ResourceInspectorPlugin,
+};
+use leafwing_input_manager::prelude::*;
+
+// the first two players share the keyboard - everyone after that needs a
+// gamepad
+const KEYBOARD_PLAYERS: usize = 2;
+pub const MAX_PLAYERS: usize = 4;
+
+pub struct PlayerPlugin;
+
+impl Plugin for PlayerPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<PlayerConfig>()
+            .init_resource::<PlayerConfig>()
+            .add_plugins(
+                ResourceInspectorPlugin::<PlayerConfig>::default()
+                    .run_if(toggle_active(false, GlobalAction::PlayerInspector)),
+            )
+            .add_systems(
+                Update,
+                (assign_gamepads, draw_player_markers).run_if(in_state(PlayingGame)),
+            );
+    }
+}
+
+/// which player a spaceship belongs to - players are numbered from 0
+#[derive(Component, Reflect, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Player(pub usize);
+
+/// the player that gets credit for whatever this (a missile, say) does
+#[derive(Component, Debug, Clone, Copy)]
+pub struct Owner(pub Player);
+
+// player_count takes effect the next time a game starts
+// spawn_spacing is how far apart the spaceships start out - they're lined up
+// around SpaceshipConfig's spawn position
+// markers are only drawn when there's more than one player to tell apart
+#[derive(Resource, Reflect, InspectorOptions, Debug, Clone)]
+#[reflect(Resource, InspectorOptions)]
+pub struct PlayerConfig {
+    pub colors:              Vec<Color>,
+    #[inspector(min = 0.5, max = 3.0, display = NumberDisplay::Slider)]
+    pub marker_radius_scale: f32,
+    #[inspector(min = 1, max = 4, display = NumberDisplay::Slider)]
+    pub player_count:        usize,
+    #[inspector(min = 0.0, max = 100.0, display = NumberDisplay::Slider)]
+    pub spawn_spacing:       f32,
+}
+
+impl Default for PlayerConfig {
+    fn default() -> Self {
+        Self {
+            colors:              vec![
+                Color::from(tailwind::CYAN_400),
+                Color::from(tailwind::ORANGE_400),
+                Color::from(tailwind::LIME_400),
+                Color::from(tailwind::FUCHSIA_400),
+            ],
+            marker_radius_scale: 1.2,
+            player_count:        1,
+            spawn_spacing:       30.,
+        }
+    }
+}
+
+impl PlayerConfig {
+    pub fn player_count(&self) -> usize { self.player_count.clamp(1, MAX_PLAYERS) }
+
+    pub fn players(&self) -> impl Iterator<Item = Player> { (0..self.player_count()).map(Player) }
+
+    pub fn color(&self, player: Player) -> Color {
+        self.colors
+            .get(player.0 % self.colors.len().max(1))
+            .copied()
+            .unwrap_or(Color::WHITE)
+    }
+
+    /// players are spread out evenly either side of `center`
+    pub fn spawn_position(&self, center: Vec3, player: Player) -> Vec3 {
+        let offset = player.0 as f32 - (self.player_count() - 1) as f32 / 2.0;
+        center + Vec3::X * offset * self.spawn_spacing
+    }
+
+    pub fn control_scheme(&self, player: Player) -> ControlScheme {
+        if self.player_count() == 1 {
+            return ControlScheme::SinglePlayer;
+        }
+
+        match player.0 {
+            0 => ControlScheme::LeftKeyboard,
+            1 => ControlScheme::RightKeyboard,
+            _ => ControlScheme::Gamepad,
+        }
+    }
+}
+
+/// the player credited for something `entity` did - a spaceship is credited to
+/// its own player and anything it fires to its Owner
+pub fn owning_player(q_owners: &Query<(Option<&Player>, Option<&Owner>)>, entity: Entity) -> Option<Player> {
+    q_owners
+        .get(entity)
+        .ok()
+        .and_then(|(player, owner)| player.copied().or(owner.map(|owner| owner.0)))
+}
+
+// gamepads are handed out to the gamepad players in the order bevy lists them -
+// until one is assigned, a gamepad player's map listens to every gamepad
+fn assign_gamepads(
+    mut q_players: Query<(&Player, &mut InputMap<SpaceshipControl>), With<Spaceship>>,
+    q_gamepads: Query<Entity, With<Gamepad>>,
+    config: Res<PlayerConfig>,
+) {
+    if config.player_count() <= KEYBOARD_PLAYERS {
+        return;
+    }
+
+    let gamepads: Vec<Entity> = q_gamepads.iter().collect();
+
+    for (player, mut input_map) in q_players.iter_mut() {
+        let Some(gamepad_index) = player.0.checked_sub(KEYBOARD_PLAYERS) else {
+            continue;
+        };
+
+        if let Some(&gamepad) = gamepads.get(gamepad_index) {
+            if input_map.gamepad() != Some(gamepad) {
+                input_map.set_gamepad(gamepad);
+            }
+        }
+    }
+}
+
+fn draw_player_markers(
+    q_players: Query<(&Player, &Transform, &Aabb), With<Spaceship>>,
+    config: Res<PlayerConfig>,
+    orientation: Res<CameraOrientation>,
+    mut gizmos: Gizmos,
+) {
+    if config.player_count() <= 1 {
+        return;
+    }
+
+    // markers lie flat in the plane the camera looks down on
+    let normal = Dir3::new(orientation.config.axis_profundus).unwrap_or(Dir3::Z);
+    let rotation = Quat::from_rotation_arc(Vec3::Z, normal.as_vec3());
+
+    for (player, transform, aabb) in q_players.iter() {
+        let radius = aabb.max_dimension() * transform.scale.max_element() * config.marker_radius_scale / 2.0;
+
+        gizmos.circle(
+            Isometry3d::new(transform.translation, rotation),
+            radius,
+            config.color(*player),
+        );
+    }
+}
@@ -0,0 +1,151 @@
+use crate::{
+    actor::{
+        actor_spawner::spawn_actor,
+        actor_template::{
+            SaucerConfig,
+            SaucerMissileConfig,
+        },
+        missile::Missile,
+        spaceship::Spaceship,
+        Aabb,
+    },
+    orientation::CameraOrientation,
+    playfield::Boundary,
+    schedule::InGameSet,
+};
+use bevy::prelude::*;
+use bevy_rapier3d::prelude::Velocity;
+use rand::Rng;
+
+pub struct SaucerPlugin;
+
+impl Plugin for SaucerPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            (spawn_saucer, steer_saucers, fire_saucer_missiles)
+                .chain()
+                .in_set(InGameSet::EntityUpdates),
+        );
+    }
+}
+
+#[derive(Component, Debug)]
+pub struct Saucer {
+    fire_timer: Timer,
+}
+
+// only one saucer hunts at a time - the spawn timer only runs while there
+// isn't one so the next one shows up a while after the last one is destroyed
+fn spawn_saucer(
+    mut commands: Commands,
+    mut config: ResMut<SaucerConfig>,
+    boundary: Res<Boundary>,
+    orientation: Res<CameraOrientation>,
+    time: Res<Time>,
+    q_saucers: Query<(), With<Saucer>>,
+) {
+    let fire_interval = config.fire_interval;
+    let saucer_config = &mut config.actor;
+
+    if !saucer_config.spawnable || !q_saucers.is_empty() {
+        return;
+    }
+
+    let Some(spawn_timer) = saucer_config.spawn_timer.as_mut() else {
+        return;
+    };
+    spawn_timer.tick(time.delta());
+
+    if !spawn_timer.just_finished() {
+        return;
+    }
+
+    spawn_actor(&mut commands, &saucer_config.oriented(&orientation), Some(boundary), None).insert(Saucer {
+        fire_timer: Timer::from_seconds(fire_interval, TimerMode::Repeating),
+    });
+}
+
+// head for the nearest spaceship the short way around the boundary wrap, easing
+// off as we get to standoff_distance and backing away if we get any closer
+fn steer_saucers(
+    mut q_saucers: Query<(&Transform, &mut Velocity), With<Saucer>>,
+    q_spaceship: Query<&Transform, With<Spaceship>>,
+    boundary: Res<Boundary>,
+    config: Res<SaucerConfig>,
+    orientation: Res<CameraOrientation>,
+    time: Res<Time>,
+) {
+    for (transform, mut velocity) in q_saucers.iter_mut() {
+        let spaceships = q_spaceship.iter().map(|spaceship| spaceship.translation);
+        let Some(to_spaceship) = boundary.nearest_wrapped_delta(transform.translation, spaceships) else {
+            continue;
+        };
+        let distance = to_spaceship.length();
+
+        let approach =
+            ((distance - config.standoff_distance) / config.standoff_distance.max(1.0)).clamp(-1.0, 1.0);
+        let desired_velocity = to_spaceship.normalize_or_zero() * config.max_speed * approach;
+
+        let steering = (config.steering * time.delta_secs()).min(1.0);
+        velocity.linvel = velocity.linvel.lerp(desired_velocity, steering);
+
+        //todo: #handl3d
+        if !orientation.config.allow_3d {
+            velocity.linvel.z = 0.0;
+        }
+    }
+}
+
+fn fire_saucer_missiles(
+    mut commands: Commands,
+    mut q_saucers: Query<(&Transform, &Velocity, &Aabb, &mut Saucer)>,
+    q_spaceship: Query<&Transform, With<Spaceship>>,
+    boundary: Res<Boundary>,
+    config: Res<SaucerConfig>,
+    missile_config: Res<SaucerMissileConfig>,
+    time: Res<Time>,
+) {
+    if q_spaceship.is_empty() {
+        return;
+    }
+
+    let mut rng = rand::thread_rng();
+
+    for (transform, velocity, aabb, mut saucer) in q_saucers.iter_mut() {
+        saucer.fire_timer.tick(time.delta());
+
+        if !saucer.fire_timer.just_finished() || !missile_config.0.spawnable {
+            continue;
+        }
+
+        let spaceships = q_spaceship.iter().map(|spaceship| spaceship.translation);
+        let Some(aim) = boundary
+            .nearest_wrapped_delta(transform.translation, spaceships)
+            .and_then(Vec3::try_normalize)
+        else {
+            continue;
+        };
+
+        let max_error = config.max_aim_error * (1.0 - config.accuracy).clamp(0.0, 1.0);
+        let error = rng.gen_range(-max_error..=max_error);
+        let aim = Quat::from_rotation_z(error) * aim;
+
+        // missiles spawn relative to a parent oriented like the spaceship model -
+        // facing +Y before it's rotated - so we fire from a stand-in parent that
+        // faces along the aim
+        let parent_transform = Transform::from_translation(transform.translation)
+            .with_scale(transform.scale)
+            .with_rotation(
+                Quat::from_rotation_arc(Vec3::Y, aim) * Quat::from_rotation_x(-std::f32::consts::FRAC_PI_2),
+            );
+
+        spawn_actor(
+            &mut commands,
+            &missile_config.0,
+            None,
+            Some((&parent_transform, velocity, aabb)),
+        )
+        .insert(Missile::new(boundary.max_missile_distance()));
+    }
+}
@@ -0,0 +1,227 @@
+use crate::{
+    actor::{
+        actor_template::SpaceshipConfig,
+        spaceship::Spaceship,
+        spaceship_control::SpaceshipControl,
+        Aabb,
+    },
+    camera::RenderLayer,
+    global_input::{
+        toggle_active,
+        GlobalAction,
+    },
+    schedule::InGameSet,
+};
+use bevy::{
+    color::palettes::tailwind,
+    prelude::*,
+    render::view::RenderLayers,
+};
+use bevy_inspector_egui::{
+    inspector_options::std_options::NumberDisplay,
+    prelude::*,
+    quick::ResourceInspectorPlugin,
+};
+use bevy_rapier3d::prelude::Restitution;
+use leafwing_input_manager::prelude::*;
+
+pub struct ShieldPlugin;
+
+impl Plugin for ShieldPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<ShieldConfig>()
+            .add_plugins(
+                ResourceInspectorPlugin::<ShieldConfig>::default()
+                    .run_if(toggle_active(false, GlobalAction::ShieldInspector)),
+            )
+            .init_resource::<ShieldConfig>()
+            .add_systems(
+                Update,
+                (attach_shield_bubble, update_shield, show_shield_bubble)
+                    .chain()
+                    .in_set(InGameSet::UserInput),
+            );
+    }
+}
+
+// energy is in the same units as CollisionDamage - one point of energy soaks up
+// one point of damage - and the shield also drains drain_per_second for as long
+// as it's held up. it recharges whenever it's down but it can't be raised again
+// until it's back up to min_energy_to_raise
+//
+// restitution replaces the spaceship's restitution while the shield is up so
+// nateroids bounce off of it
+#[derive(Resource, Reflect, InspectorOptions, Debug, PartialEq, Clone, Copy)]
+#[reflect(Resource, InspectorOptions)]
+pub struct ShieldConfig {
+    #[inspector(min = 0.0, max = 1.0, display = NumberDisplay::Slider)]
+    pub bubble_alpha:        f32,
+    pub bubble_color:        Color,
+    #[inspector(min = 0.1, max = 2.0, display = NumberDisplay::Slider)]
+    pub bubble_scale:        f32,
+    #[inspector(min = 0.0, max = 200.0, display = NumberDisplay::Slider)]
+    pub drain_per_second:    f32,
+    #[inspector(min = 10.0, max = 1000.0, display = NumberDisplay::Slider)]
+    pub max_energy:          f32,
+    #[inspector(min = 0.0, max = 200.0, display = NumberDisplay::Slider)]
+    pub min_energy_to_raise: f32,
+    #[inspector(min = 0.0, max = 200.0, display = NumberDisplay::Slider)]
+    pub recharge_per_second: f32,
+    #[inspector(min = 0.0, max = 2.0, display = NumberDisplay::Slider)]
+    pub restitution:         f32,
+}
+
+impl Default for ShieldConfig {
+    fn default() -> Self {
+        Self {
+            bubble_alpha:        0.25,
+            bubble_color:        Color::from(tailwind::CYAN_400),
+            bubble_scale:        0.75,
+            drain_per_second:    40.,
+            max_energy:          200.,
+            min_energy_to_raise: 25.,
+            recharge_per_second: 20.,
+            restitution:         1.5,
+        }
+    }
+}
+
+#[derive(Component, Debug, Clone)]
+pub struct Shield {
+    pub active: bool,
+    pub energy: f32,
+}
+
+impl Shield {
+    pub fn new(config: &ShieldConfig) -> Self {
+        Self {
+            active: false,
+            energy: config.max_energy,
+        }
+    }
+
+    /// soaks up as much of the damage as the remaining energy allows and
+    /// returns whatever gets through
+    pub fn absorb(&mut self, damage: f32) -> f32 {
+        if !self.active {
+            return damage;
+        }
+
+        let absorbed = damage.min(self.energy);
+        self.energy -= absorbed;
+        damage - absorbed
+    }
+}
+
+#[derive(Component)]
+struct ShieldBubble;
+
+fn attach_shield_bubble(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    config: Res<ShieldConfig>,
+    q_shields: Query<(Entity, &Aabb), Added<Shield>>,
+) {
+    for (entity, aabb) in q_shields.iter() {
+        let radius = aabb.max_dimension() * config.bubble_scale;
+
+        let material = materials.add(StandardMaterial {
+            base_color: config.bubble_color.with_alpha(config.bubble_alpha),
+            emissive: LinearRgba::from(config.bubble_color) * 0.5,
+            alpha_mode: AlphaMode::Blend,
+            ..default()
+        });
+
+        commands.entity(entity).with_children(|parent| {
+            parent.spawn((
+                ShieldBubble,
+                Mesh3d(meshes.add(Sphere::new(radius))),
+                MeshMaterial3d(material),
+                Transform::from_translation(aabb.center()),
+                Visibility::Hidden,
+                RenderLayers::from_layers(RenderLayer::Game.layers()),
+            ));
+        });
+    }
+}
+
+fn update_shield(
+    mut q_spaceship: Query<(&ActionState<SpaceshipControl>, &mut Shield, &mut Restitution), With<Spaceship>>,
+    config: Res<ShieldConfig>,
+    spaceship_config: Res<SpaceshipConfig>,
+    time: Res<Time>,
+) {
+    let delta_seconds = time.delta_secs();
+
+    for (controls, mut shield, mut restitution) in q_spaceship.iter_mut() {
+        let wants_shield = controls.pressed(&SpaceshipControl::Shield);
+
+        shield.active = if shield.active {
+            wants_shield && shield.energy > 0.0
+        } else {
+            wants_shield && shield.energy >= config.min_energy_to_raise
+        };
+
+        shield.energy = if shield.active {
+            (shield.energy - config.drain_per_second * delta_seconds).max(0.0)
+        } else {
+            (shield.energy + config.recharge_per_second * delta_seconds).min(config.max_energy)
+        };
+
+        restitution.coefficient = if shield.active {
+            config.restitution
+        } else {
+            spaceship_config.0.restitution
+        };
+    }
+}
+
+fn show_shield_bubble(
+    q_shields: Query<(&Shield, &Children)>,
+    mut q_bubbles: Query<&mut Visibility, With<ShieldBubble>>,
+) {
+    for (shield, children) in q_shields.iter() {
+        for &child in children.iter() {
+            if let Ok(mut visibility) = q_bubbles.get_mut(child) {
+                *visibility = if shield.active {
+                    Visibility::Inherited
+                } else {
+                    Visibility::Hidden
+                };
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn shield(active: bool, energy: f32) -> Shield { Shield { active, energy } }
+
+    #[test]
+    fn lowered_shield_lets_everything_through() {
+        let mut shield = shield(false, 100.);
+
+        assert_eq!(shield.absorb(30.), 30.);
+        assert_eq!(shield.energy, 100.);
+    }
+
+    #[test]
+    fn raised_shield_soaks_up_damage_it_has_energy_for() {
+        let mut shield = shield(true, 100.);
+
+        assert_eq!(shield.absorb(30.), 0.);
+        assert_eq!(shield.energy, 70.);
+    }
+
+    #[test]
+    fn overflow_gets_through_once_energy_runs_out() {
+        let mut shield = shield(true, 20.);
+
+        assert_eq!(shield.absorb(30.), 10.);
+        assert_eq!(shield.energy, 0.);
+        assert_eq!(shield.absorb(5.), 5.);
+    }
+}
@@ -1,13 +1,38 @@
 use crate::{
     actor::{
-        actor_spawner::spawn_actor,
+        actor_spawner::{
+            spawn_actor,
+            ActorConfig,
+            SpawnPositionBehavior,
+        },
         actor_template::SpaceshipConfig,
-        spaceship_control::SpaceshipControl,
+        nateroid::Nateroid,
+        player::{
+            Player,
+            PlayerConfig,
+        },
+        shield::{
+            Shield,
+            ShieldConfig,
+        },
+        versus::VersusMatch,
+        weapon::Weapon,
     },
+    bindings::InputBindings,
     schedule::InGameSet,
-    state::GameState,
+    state::{
+        GameState,
+        PlayingGame,
+    },
+};
+use bevy::{
+    ecs::system::EntityCommands,
+    prelude::*,
+};
+use bevy_rapier3d::prelude::{
+    Damping,
+    ExternalImpulse,
 };
-use bevy::prelude::*;
 use leafwing_input_manager::prelude::*;
 
 #[derive(Component, Debug)]
@@ -20,40 +45,261 @@ pub struct SpaceshipPlugin;
 impl Plugin for SpaceshipPlugin {
     // make sure this is done after asset_loader has run
     fn build(&self, app: &mut App) {
-        // we can enter InGame a couple of ways - when we do, spawn a spaceship
-        app.add_systems(OnExit(GameState::Splash), spawn_spaceship)
-            .add_systems(OnExit(GameState::GameOver), spawn_spaceship)
-            // check if spaceship is destroyed...this will change the GameState
-            .add_systems(Update, spaceship_destroyed.in_set(InGameSet::EntityUpdates));
+        app.register_type::<LivesConfig>()
+            .init_resource::<LivesConfig>()
+            .init_resource::<Lives>()
+            // we can enter InGame a couple of ways - when we do, spawn a spaceship
+            // for every player
+            .add_systems(OnEnter(PlayingGame), (reset_lives, spawn_spaceships))
+            // check if spaceships are destroyed...this will either start waiting on a
+            // respawn or, once every player is out of lives, change the GameState
+            .add_systems(
+                Update,
+                (spaceship_destroyed, respawn_spaceship, update_invulnerability)
+                    .chain()
+                    .in_set(InGameSet::EntityUpdates),
+            );
+    }
+}
+
+// starting_lives includes the ship you start with
+// respawn_clear_radius - we won't respawn until no nateroid is this close to
+// the spawn position so you don't die the instant you come back
+#[derive(Resource, Reflect, Debug, Clone)]
+#[reflect(Resource)]
+pub struct LivesConfig {
+    pub blink_interval:          f32,
+    pub invulnerability_seconds: f32,
+    pub respawn_clear_radius:    f32,
+    pub starting_lives:          u32,
+}
+
+impl Default for LivesConfig {
+    fn default() -> Self {
+        Self {
+            blink_interval:          0.1,
+            invulnerability_seconds: 3.,
+            respawn_clear_radius:    30.,
+            starting_lives:          3,
+        }
     }
 }
 
-fn spawn_spaceship(mut commands: Commands, spaceship_config: Res<SpaceshipConfig>) {
+#[derive(Debug, Default, Clone)]
+pub struct PlayerLives {
+    pub remaining:        u32,
+    pub awaiting_respawn: bool,
+}
+
+/// indexed by Player - a player with no lives remaining is out but everyone
+/// else plays on
+#[derive(Resource, Debug, Default)]
+pub struct Lives(pub Vec<PlayerLives>);
+
+impl Lives {
+    fn all_out(&self) -> bool { self.0.iter().all(|lives| lives.remaining == 0) }
+}
+
+// while this is on the spaceship it takes no collision damage - see
+// apply_collision_damage - and blinks so you can tell
+#[derive(Component, Debug)]
+pub struct Invulnerable {
+    blink_timer: Timer,
+    timer:       Timer,
+}
+
+impl Invulnerable {
+    fn new(config: &LivesConfig) -> Self {
+        Self {
+            blink_timer: Timer::from_seconds(config.blink_interval, TimerMode::Repeating),
+            timer:       Timer::from_seconds(config.invulnerability_seconds, TimerMode::Once),
+        }
+    }
+}
+
+fn reset_lives(mut lives: ResMut<Lives>, config: Res<LivesConfig>, player_config: Res<PlayerConfig>) {
+    let player_lives = PlayerLives {
+        remaining:        config.starting_lives,
+        awaiting_respawn: false,
+    };
+
+    *lives = Lives(vec![player_lives; player_config.player_count()]);
+}
+
+fn spawn_spaceships(
+    mut commands: Commands,
+    spaceship_config: Res<SpaceshipConfig>,
+    shield_config: Res<ShieldConfig>,
+    player_config: Res<PlayerConfig>,
+    bindings: Res<InputBindings>,
+) {
     if !spaceship_config.0.spawnable {
         return;
     }
 
-    let spaceship_input = InputManagerBundle::with_map(SpaceshipControl::generate_input_map());
+    for player in player_config.players() {
+        spawn_spaceship_actor(
+            &mut commands,
+            &spaceship_config,
+            &shield_config,
+            &player_config,
+            &bindings,
+            player,
+        );
+    }
+}
 
-    spawn_actor(&mut commands, &spaceship_config.0, None, None)
+fn spawn_spaceship_actor<'a>(
+    commands: &'a mut Commands,
+    spaceship_config: &SpaceshipConfig,
+    shield_config: &ShieldConfig,
+    player_config: &PlayerConfig,
+    bindings: &InputBindings,
+    player: Player,
+) -> EntityCommands<'a> {
+    let input_map = bindings.spaceship_input_map(player_config.control_scheme(player));
+    let spaceship_input = InputManagerBundle::with_map(input_map);
+
+    // players line up side by side rather than all spawning on top of each other
+    let position = spawn_position(spaceship_config, player_config, player);
+    let player_actor_config = ActorConfig {
+        spawn_position_behavior: SpawnPositionBehavior::Fixed(position),
+        ..spaceship_config.0.clone()
+    };
+
+    let mut spaceship = spawn_actor(commands, &player_actor_config, None, None);
+    spaceship
         .insert(spaceship_input)
+        .insert(Shield::new(shield_config))
+        .insert(Weapon::default())
+        .insert(ExternalImpulse::default())
+        .insert(Damping::default())
+        .insert(player)
         .insert(Spaceship);
+    spaceship
 }
 
-// check if spaceship exists or not - query if get_single()
-// there should only be one - if it returns an error then the
-// spaceship doesn't exist
+fn spawn_position(spaceship_config: &SpaceshipConfig, player_config: &PlayerConfig, player: Player) -> Vec3 {
+    let center = match spaceship_config.0.spawn_position_behavior {
+        SpawnPositionBehavior::Fixed(position) => position,
+        _ => Vec3::ZERO,
+    };
+
+    player_config.spawn_position(center, player)
+}
+
+// if a player's spaceship is gone they've lost a life - if that was their last
+// one they're out, otherwise respawn_spaceship brings them back once it's safe.
+// the game is over once everyone is out. a versus match doesn't cost lives -
+// it ends on frags or time instead
 fn spaceship_destroyed(
     mut next_state: ResMut<NextState<GameState>>,
-    query: Query<Entity, With<Spaceship>>,
+    mut lives: ResMut<Lives>,
+    query: Query<&Player, With<Spaceship>>,
     state: Res<State<GameState>>,
+    versus_match: Res<VersusMatch>,
 ) {
-    if query.get_single().is_err() {
-        println!(
-            "spaceship destroyed: {:?}, count {:?}",
-            state,
-            query.iter().count()
-        );
+    for (index, player_lives) in lives.0.iter_mut().enumerate() {
+        let player = Player(index);
+
+        if player_lives.remaining == 0
+            || player_lives.awaiting_respawn
+            || query.iter().any(|&spaceship_player| spaceship_player == player)
+        {
+            continue;
+        }
+
+        if versus_match.active {
+            player_lives.awaiting_respawn = true;
+            continue;
+        }
+
+        player_lives.remaining = player_lives.remaining.saturating_sub(1);
+
+        if player_lives.remaining == 0 {
+            println!("player {} destroyed: {:?}, no lives remaining", index + 1, state);
+        } else {
+            println!(
+                "player {} destroyed: {:?}, lives remaining {}",
+                index + 1,
+                state,
+                player_lives.remaining
+            );
+            player_lives.awaiting_respawn = true;
+        }
+    }
+
+    if lives.all_out() {
+        println!("every player is out of lives");
         next_state.set(GameState::GameOver);
     }
 }
+
+#[allow(clippy::too_many_arguments)]
+fn respawn_spaceship(
+    mut commands: Commands,
+    mut lives: ResMut<Lives>,
+    config: Res<LivesConfig>,
+    spaceship_config: Res<SpaceshipConfig>,
+    shield_config: Res<ShieldConfig>,
+    player_config: Res<PlayerConfig>,
+    bindings: Res<InputBindings>,
+    q_nateroids: Query<&Transform, With<Nateroid>>,
+) {
+    if !spaceship_config.0.spawnable {
+        return;
+    }
+
+    for (index, player_lives) in lives.0.iter_mut().enumerate() {
+        if !player_lives.awaiting_respawn {
+            continue;
+        }
+
+        let player = Player(index);
+        let spawn_position = spawn_position(&spaceship_config, &player_config, player);
+
+        let area_clear = q_nateroids
+            .iter()
+            .all(|transform| transform.translation.distance(spawn_position) >= config.respawn_clear_radius);
+
+        if !area_clear {
+            continue;
+        }
+
+        player_lives.awaiting_respawn = false;
+
+        spawn_spaceship_actor(
+            &mut commands,
+            &spaceship_config,
+            &shield_config,
+            &player_config,
+            &bindings,
+            player,
+        )
+        .insert(Invulnerable::new(&config));
+    }
+}
+
+fn update_invulnerability(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut query: Query<(Entity, &mut Invulnerable, &mut Visibility), With<Spaceship>>,
+) {
+    for (entity, mut invulnerable, mut visibility) in query.iter_mut() {
+        invulnerable.timer.tick(time.delta());
+        invulnerable.blink_timer.tick(time.delta());
+
+        if invulnerable.timer.finished() {
+            *visibility = Visibility::Inherited;
+            commands.entity(entity).remove::<Invulnerable>();
+            continue;
+        }
+
+        if invulnerable.blink_timer.just_finished() {
+            *visibility = match *visibility {
+                Visibility::Hidden => Visibility::Inherited,
+                _ => Visibility::Hidden,
+            };
+        }
+    }
+}
@@ -11,11 +11,9 @@ use crate::{
         toggle_active,
         GlobalAction,
     },
-    orientation::{
-        CameraOrientation,
-        OrientationType,
-    },
+    orientation::CameraOrientation,
     schedule::InGameSet,
+    state::IsPaused,
 };
 use bevy::prelude::*;
 use bevy_inspector_egui::{
@@ -23,11 +21,19 @@ use bevy_inspector_egui::{
     prelude::*,
     quick::ResourceInspectorPlugin,
 };
-use bevy_rapier3d::dynamics::Velocity;
+use bevy_rapier3d::{
+    dynamics::{
+        Damping,
+        ExternalImpulse,
+        Velocity,
+    },
+    geometry::ColliderMassProperties,
+};
 use leafwing_input_manager::{
     action_state::ActionState,
     input_map::InputMap,
     plugin::InputManagerPlugin,
+    prelude::GamepadControlDirection,
     Actionlike,
 };
 use strum::{
@@ -49,32 +55,82 @@ impl Plugin for SpaceshipControlPlugin {
             .add_plugins(InputManagerPlugin::<SpaceshipControl>::default())
             .init_resource::<ActionState<SpaceshipControl>>()
             .insert_resource(SpaceshipControl::generate_input_map())
+            // the pause menu uses some of the same keys - a spaceship shouldn't
+            // come out of the pause with fire or shield still held down
+            .add_systems(OnEnter(IsPaused::Paused), disable_spaceship_controls)
+            .add_systems(OnExit(IsPaused::Paused), enable_spaceship_controls)
             .add_systems(
                 Update,
-                (spaceship_movement_controls, toggle_continuous_fire)
+                (level_spaceships, spaceship_movement_controls, toggle_continuous_fire)
                     .chain()
                     .in_set(InGameSet::UserInput),
             );
     }
 }
 
+// arcade flight bleeds off speed and spin on its own - newtonian keeps you
+// drifting until you thrust the other way or hit the brake
+#[derive(Reflect, Debug, Default, PartialEq, Eq, Clone, Copy)]
+pub enum FlightModel {
+    #[default]
+    Arcade,
+    Newtonian,
+}
+
+// linear_damping and angular_damping only apply to FlightModel::Arcade -
+// brake_damping applies while braking no matter which model you're flying
 #[derive(Resource, Reflect, InspectorOptions, Debug, PartialEq, Clone, Copy)]
 #[reflect(Resource, InspectorOptions)]
 pub struct SpaceshipControlConfig {
     #[inspector(min = 30., max = 300.0, display = NumberDisplay::Slider)]
-    pub acceleration:   f32,
+    pub acceleration:         f32,
+    #[inspector(min = 0.0, max = 10.0, display = NumberDisplay::Slider)]
+    pub angular_damping:      f32,
+    #[inspector(min = 0.0, max = 10.0, display = NumberDisplay::Slider)]
+    pub brake_damping:        f32,
+    pub flight_model:         FlightModel,
+    #[inspector(min = 0.0, max = 5.0, display = NumberDisplay::Slider)]
+    pub linear_damping:       f32,
     #[inspector(min = 50., max = 300.0, display = NumberDisplay::Slider)]
-    pub max_speed:      f32,
+    pub max_speed:            f32,
+    #[inspector(min = 0.0, max = 300.0, display = NumberDisplay::Slider)]
+    pub reverse_acceleration: f32,
     #[inspector(min = 1.0, max = 10.0, display = NumberDisplay::Slider)]
-    pub rotation_speed: f32,
+    pub rotation_speed:       f32,
+    #[inspector(min = 0.0, max = 300.0, display = NumberDisplay::Slider)]
+    pub strafe_acceleration:  f32,
 }
 
 impl Default for SpaceshipControlConfig {
     fn default() -> Self {
         Self {
-            acceleration:   60.,
-            rotation_speed: 5.0,
-            max_speed:      80.,
+            acceleration:         60.,
+            angular_damping:      1.,
+            brake_damping:        3.,
+            flight_model:         FlightModel::default(),
+            linear_damping:       0.3,
+            max_speed:            80.,
+            reverse_acceleration: 30.,
+            rotation_speed:       5.0,
+            strafe_acceleration:  40.,
+        }
+    }
+}
+
+impl SpaceshipControlConfig {
+    fn damping(&self, braking: bool) -> Damping {
+        let (linear_damping, angular_damping) = match self.flight_model {
+            FlightModel::Arcade => (self.linear_damping, self.angular_damping),
+            FlightModel::Newtonian => (0.0, 0.0),
+        };
+
+        Damping {
+            linear_damping: if braking {
+                linear_damping.max(self.brake_damping)
+            } else {
+                linear_damping
+            },
+            angular_damping,
         }
     }
 }
@@ -84,8 +140,19 @@ impl Default for SpaceshipControlConfig {
 #[derive(Actionlike, EnumIter, PartialEq, Eq, Clone, Copy, Hash, Debug, Reflect)]
 pub enum SpaceshipControl {
     Accelerate,
+    Brake,
     ContinuousFire,
+    CycleWeapon,
     Fire,
+    Hyperspace,
+    PitchDown,
+    PitchUp,
+    Reverse,
+    RollLeft,
+    RollRight,
+    Shield,
+    StrafeLeft,
+    StrafeRight,
     TurnLeft,
     TurnRight,
 }
@@ -97,48 +164,197 @@ impl SpaceshipControl {
             Self::Accelerate => input_map
                 .with(action, KeyCode::KeyW)
                 .with(action, KeyCode::ArrowUp),
+            Self::Reverse => input_map
+                .with(action, KeyCode::KeyS)
+                .with(action, KeyCode::ArrowDown),
             Self::TurnLeft => input_map
                 .with(action, KeyCode::KeyA)
                 .with(action, KeyCode::ArrowLeft),
             Self::TurnRight => input_map
                 .with(action, KeyCode::KeyD)
                 .with(action, KeyCode::ArrowRight),
+            Self::StrafeLeft => input_map.with(action, KeyCode::KeyZ),
+            Self::StrafeRight => input_map.with(action, KeyCode::KeyC),
+            Self::Brake => input_map.with(action, KeyCode::KeyX),
+            Self::PitchUp => input_map.with(action, KeyCode::KeyI),
+            Self::PitchDown => input_map.with(action, KeyCode::KeyK),
+            Self::RollLeft => input_map.with(action, KeyCode::KeyJ),
+            Self::RollRight => input_map.with(action, KeyCode::KeyL),
             Self::Fire => input_map.with(action, KeyCode::Space),
             Self::ContinuousFire => input_map.with(action, KeyCode::KeyF),
+            Self::CycleWeapon => input_map.with(action, KeyCode::KeyQ),
+            Self::Hyperspace => input_map.with(action, KeyCode::KeyH),
+            Self::Shield => input_map.with(action, KeyCode::KeyR),
+        })
+    }
+
+    // with more than one player the keyboard is split in half - the left half
+    // keeps the single player keys except hyperspace, which moves over to E
+    pub fn left_keyboard_input_map() -> InputMap<Self> {
+        Self::iter().fold(InputMap::default(), |input_map, action| match action {
+            Self::Accelerate => input_map.with(action, KeyCode::KeyW),
+            Self::Reverse => input_map.with(action, KeyCode::KeyS),
+            Self::TurnLeft => input_map.with(action, KeyCode::KeyA),
+            Self::TurnRight => input_map.with(action, KeyCode::KeyD),
+            Self::StrafeLeft => input_map.with(action, KeyCode::KeyZ),
+            Self::StrafeRight => input_map.with(action, KeyCode::KeyC),
+            Self::Brake => input_map.with(action, KeyCode::KeyX),
+            Self::PitchUp => input_map.with(action, KeyCode::KeyT),
+            Self::PitchDown => input_map.with(action, KeyCode::KeyG),
+            Self::RollLeft => input_map.with(action, KeyCode::KeyV),
+            Self::RollRight => input_map.with(action, KeyCode::KeyB),
+            Self::Fire => input_map.with(action, KeyCode::Space),
+            Self::ContinuousFire => input_map.with(action, KeyCode::KeyF),
+            Self::CycleWeapon => input_map.with(action, KeyCode::KeyQ),
+            Self::Hyperspace => input_map.with(action, KeyCode::KeyE),
+            Self::Shield => input_map.with(action, KeyCode::KeyR),
+        })
+    }
+
+    pub fn right_keyboard_input_map() -> InputMap<Self> {
+        Self::iter().fold(InputMap::default(), |input_map, action| match action {
+            Self::Accelerate => input_map.with(action, KeyCode::ArrowUp),
+            Self::Reverse => input_map.with(action, KeyCode::ArrowDown),
+            Self::TurnLeft => input_map.with(action, KeyCode::ArrowLeft),
+            Self::TurnRight => input_map.with(action, KeyCode::ArrowRight),
+            Self::StrafeLeft => input_map.with(action, KeyCode::Delete),
+            Self::StrafeRight => input_map.with(action, KeyCode::PageDown),
+            Self::Brake => input_map.with(action, KeyCode::End),
+            Self::PitchUp => input_map.with(action, KeyCode::Numpad8),
+            Self::PitchDown => input_map.with(action, KeyCode::Numpad5),
+            Self::RollLeft => input_map.with(action, KeyCode::Numpad4),
+            Self::RollRight => input_map.with(action, KeyCode::Numpad6),
+            Self::Fire => input_map.with(action, KeyCode::ControlRight),
+            Self::ContinuousFire => input_map.with(action, KeyCode::Period),
+            Self::CycleWeapon => input_map.with(action, KeyCode::Comma),
+            Self::Hyperspace => input_map.with(action, KeyCode::Slash),
+            Self::Shield => input_map.with(action, KeyCode::Enter),
+        })
+    }
+
+    pub fn gamepad_input_map() -> InputMap<Self> {
+        Self::iter().fold(InputMap::default(), |input_map, action| match action {
+            Self::Accelerate => input_map.with(action, GamepadButton::RightTrigger2),
+            Self::Reverse => input_map.with(action, GamepadButton::LeftTrigger2),
+            Self::TurnLeft => input_map.with(action, GamepadButton::DPadLeft),
+            Self::TurnRight => input_map.with(action, GamepadButton::DPadRight),
+            Self::StrafeLeft => input_map.with(action, GamepadButton::LeftTrigger),
+            Self::StrafeRight => input_map.with(action, GamepadButton::RightTrigger),
+            Self::Brake => input_map.with(action, GamepadButton::DPadDown),
+            Self::PitchUp => input_map.with(action, GamepadControlDirection::RIGHT_UP),
+            Self::PitchDown => input_map.with(action, GamepadControlDirection::RIGHT_DOWN),
+            Self::RollLeft => input_map.with(action, GamepadControlDirection::RIGHT_LEFT),
+            Self::RollRight => input_map.with(action, GamepadControlDirection::RIGHT_RIGHT),
+            Self::Fire => input_map.with(action, GamepadButton::South),
+            Self::ContinuousFire => input_map.with(action, GamepadButton::West),
+            Self::CycleWeapon => input_map.with(action, GamepadButton::North),
+            Self::Hyperspace => input_map.with(action, GamepadButton::East),
+            Self::Shield => input_map.with(action, GamepadButton::DPadUp),
         })
     }
 }
 
+/// which of the input maps a player flies with - one player gets the whole
+/// keyboard, otherwise it's split in half and everyone past player 2 is on a
+/// gamepad. these are the defaults - InputBindings layers any rebinding on top
+#[derive(EnumIter, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ControlScheme {
+    Gamepad,
+    LeftKeyboard,
+    RightKeyboard,
+    SinglePlayer,
+}
+
+impl ControlScheme {
+    pub fn default_input_map(self) -> InputMap<SpaceshipControl> {
+        match self {
+            Self::Gamepad => SpaceshipControl::gamepad_input_map(),
+            Self::LeftKeyboard => SpaceshipControl::left_keyboard_input_map(),
+            Self::RightKeyboard => SpaceshipControl::right_keyboard_input_map(),
+            Self::SinglePlayer => SpaceshipControl::generate_input_map(),
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Gamepad => "gamepads",
+            Self::LeftKeyboard => "player 1 of 2+",
+            Self::RightKeyboard => "player 2 of 2+",
+            Self::SinglePlayer => "one player",
+        }
+    }
+
+    // how it's written in the bindings file
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Gamepad => "gamepad",
+            Self::LeftKeyboard => "left_keyboard",
+            Self::RightKeyboard => "right_keyboard",
+            Self::SinglePlayer => "single_player",
+        }
+    }
+}
+
+#[allow(clippy::type_complexity)]
 fn spaceship_movement_controls(
-    mut q_spaceship: Query<(&mut Transform, &mut Velocity), With<Spaceship>>,
+    mut q_spaceship: Query<
+        (
+            &mut Transform,
+            &mut Velocity,
+            &ColliderMassProperties,
+            &mut ExternalImpulse,
+            &mut Damping,
+            &ActionState<SpaceshipControl>,
+        ),
+        With<Spaceship>,
+    >,
     q_camera: Query<&Transform, (With<PrimaryCamera>, Without<Spaceship>)>,
-    q_input_map: Query<&ActionState<SpaceshipControl>>,
     spaceship_config: Res<SpaceshipConfig>,
     movement_config: Res<SpaceshipControlConfig>,
     time: Res<Time>,
     orientation_mode: Res<CameraOrientation>,
 ) {
-    if let Ok(camera_transform) = q_camera.get_single() {
-        // we can use this because there is only exactly one spaceship - so we're not
-        // looping over the query
-        if let Ok((mut spaceship_transform, mut velocity)) = q_spaceship.get_single_mut() {
-            // dynamically update from inspector while game is running to change size
-            spaceship_transform.scale = Vec3::splat(spaceship_config.0.scalar);
+    let Ok(camera_transform) = q_camera.get_single() else {
+        return;
+    };
+
+    // every player's spaceship carries its own input map so each one only
+    // responds to its own controls
+    for (mut spaceship_transform, mut velocity, mass_properties, mut impulse, mut damping, controls) in
+        q_spaceship.iter_mut()
+    {
+        // dynamically update from inspector while game is running to change size
+        spaceship_transform.scale = Vec3::splat(spaceship_config.0.scalar);
+
+        let delta_seconds = time.delta_secs();
+        let rotation_step = movement_config.rotation_speed * delta_seconds;
 
-            let controls = q_input_map.single();
+        if orientation_mode.config.allow_3d {
+            // six degrees of freedom - yaw, pitch and roll around the spaceship's
+            // own axes. its up is local y and it heads toward local z so turning
+            // right is a negative yaw
+            let yaw = rotation_input(controls, SpaceshipControl::TurnLeft, SpaceshipControl::TurnRight);
+            let pitch = rotation_input(controls, SpaceshipControl::PitchDown, SpaceshipControl::PitchUp);
+            let roll = rotation_input(controls, SpaceshipControl::RollRight, SpaceshipControl::RollLeft);
+
+            if yaw != 0.0 || pitch != 0.0 || roll != 0.0 {
+                velocity.angvel = Vec3::ZERO;
+            }
 
+            spaceship_transform.rotate_local_y(yaw * rotation_step);
+            spaceship_transform.rotate_local_x(pitch * rotation_step);
+            spaceship_transform.rotate_local_z(roll * rotation_step);
+        } else {
             let mut rotation = 0.0;
-            let delta_seconds = time.delta_secs();
-            let rotation_speed = movement_config.rotation_speed;
 
             if controls.pressed(&SpaceshipControl::TurnRight) {
                 // right
                 velocity.angvel.z = 0.0;
-                rotation = rotation_speed * delta_seconds;
+                rotation = rotation_step;
             } else if controls.pressed(&SpaceshipControl::TurnLeft) {
                 // left
                 velocity.angvel.z = 0.0;
-                rotation = -rotation_speed * delta_seconds;
+                rotation = -rotation_step;
             }
 
             let camera_forward = camera_transform.forward();
@@ -150,60 +366,123 @@ fn spaceship_movement_controls(
 
             // rotate around the z-axis
             spaceship_transform.rotate_z(rotation);
+        }
 
-            let max_speed = movement_config.max_speed;
-            let accel = movement_config.acceleration;
-
-            if controls.pressed(&SpaceshipControl::Accelerate) {
-                apply_acceleration(
-                    &mut velocity,
-                    -spaceship_transform.forward().as_vec3(),
-                    accel,
-                    max_speed,
-                    delta_seconds,
-                    orientation_mode,
-                );
-            }
-
-            /* let mut roll = 0.0;
+        // the model faces backwards so we head toward -forward - strafing is
+        // across that in the plane we fly in
+        let heading = -spaceship_transform.forward().as_vec3();
+        let strafe = heading.cross(spaceship_transform.up().as_vec3());
 
-               if keyboard_input.pressed(ShiftLeft) {
-                roll = -SPACESHIP_ROLL_SPEED * time.delta_seconds();
-            } else if keyboard_input.pressed(ControlLeft) {
-                roll = SPACESHIP_ROLL_SPEED * time.delta_seconds();
-            }*/
+        let mut thrust = Vec3::ZERO;
 
-            // rotate around the local z-axis
-            // the rotation is relative to the current rotation
-            // transform.rotate_local_z(roll);
+        if controls.pressed(&SpaceshipControl::Accelerate) {
+            thrust += heading * movement_config.acceleration;
+        }
+        if controls.pressed(&SpaceshipControl::Reverse) {
+            thrust -= heading * movement_config.reverse_acceleration;
+        }
+        if controls.pressed(&SpaceshipControl::StrafeLeft) {
+            thrust -= strafe * movement_config.strafe_acceleration;
         }
+        if controls.pressed(&SpaceshipControl::StrafeRight) {
+            thrust += strafe * movement_config.strafe_acceleration;
+        }
+
+        let mass = match mass_properties {
+            ColliderMassProperties::Mass(mass) => *mass,
+            _ => 1.0,
+        };
+
+        apply_thrust(
+            &mut impulse,
+            &velocity,
+            thrust,
+            mass,
+            movement_config.max_speed,
+            delta_seconds,
+            &orientation_mode,
+        );
+
+        *damping = movement_config.damping(controls.pressed(&SpaceshipControl::Brake));
     }
 }
 
-fn apply_acceleration(
-    velocity: &mut Velocity,
-    direction: Vec3,
-    acceleration: f32,
-    max_speed: f32,
-    delta_seconds: f32,
+fn disable_spaceship_controls(mut q_controls: Query<&mut ActionState<SpaceshipControl>, With<Spaceship>>) {
+    for mut controls in q_controls.iter_mut() {
+        controls.disable();
+    }
+}
+
+fn enable_spaceship_controls(mut q_controls: Query<&mut ActionState<SpaceshipControl>, With<Spaceship>>) {
+    for mut controls in q_controls.iter_mut() {
+        controls.enable();
+    }
+}
+
+// dropping out of 3d puts every spaceship back upright in the plane - it keeps
+// heading the same way as far as the plane allows so nobody gets turned around
+fn level_spaceships(
+    mut q_spaceship: Query<(&mut Transform, &mut Velocity), With<Spaceship>>,
     orientation: Res<CameraOrientation>,
 ) {
-    let proposed_velocity = velocity.linvel + direction * (acceleration * delta_seconds);
-    let proposed_speed = proposed_velocity.length();
+    if !orientation.is_changed() || orientation.config.allow_3d {
+        return;
+    }
 
-    // Ensure we're not exceeding max velocity
-    if proposed_speed > max_speed {
-        velocity.linvel = proposed_velocity.normalize() * max_speed;
-    } else {
-        velocity.linvel = proposed_velocity;
+    for (mut transform, mut velocity) in q_spaceship.iter_mut() {
+        let forward = transform.forward().as_vec3().with_z(0.0).try_normalize().unwrap_or(Vec3::Y);
+
+        transform.rotation = Transform::IDENTITY.looking_to(forward, Vec3::Z).rotation;
+        velocity.angvel = Vec3::new(0.0, 0.0, velocity.angvel.z);
     }
+}
 
-    //todo: #handl3d
-    match orientation.orientation {
-        // in 3d we can accelerate in all dirs
-        OrientationType::BehindSpaceship3D => (),
-        _ => velocity.linvel.z = 0.0, // Force the `z` value of velocity.linvel to be 0
+// 1.0 while positive is held, -1.0 for negative and nothing if it's both or
+// neither
+fn rotation_input(
+    controls: &ActionState<SpaceshipControl>,
+    positive: SpaceshipControl,
+    negative: SpaceshipControl,
+) -> f32 {
+    let mut input = 0.0;
+    if controls.pressed(&positive) {
+        input += 1.0;
+    }
+    if controls.pressed(&negative) {
+        input -= 1.0;
     }
+    input
+}
+
+// thrust is handed to rapier as an impulse rather than written into the
+// velocity - once we're at max_speed we drop whatever part of the thrust would
+// take us any faster so you can still turn and slow down
+fn apply_thrust(
+    impulse: &mut ExternalImpulse,
+    velocity: &Velocity,
+    mut thrust: Vec3,
+    mass: f32,
+    max_speed: f32,
+    delta_seconds: f32,
+    orientation: &CameraOrientation,
+) {
+    // in 3d we can accelerate in all dirs - the same flag that frees up
+    // rotation and the planar locks
+    if !orientation.config.allow_3d {
+        thrust.z = 0.0;
+    }
+
+    if velocity.linvel.length() >= max_speed {
+        if let Some(direction) = velocity.linvel.try_normalize() {
+            let speeding_up = thrust.dot(direction);
+            if speeding_up > 0.0 {
+                thrust -= direction * speeding_up;
+            }
+        }
+    }
+
+    // impulses accumulate until rapier's next step so frames between steps all count
+    impulse.impulse += thrust * mass * delta_seconds;
 }
 
 // todo: how can i avoid setting this allow - i'm guessing a system param would
@@ -213,7 +492,7 @@ fn toggle_continuous_fire(
     mut commands: Commands,
     q_spaceship: Query<(Entity, &ActionState<SpaceshipControl>, Option<&ContinuousFire>), With<Spaceship>>,
 ) {
-    if let Ok((entity, control, continuous)) = q_spaceship.get_single() {
+    for (entity, control, continuous) in q_spaceship.iter() {
         if control.just_pressed(&SpaceshipControl::ContinuousFire) {
             if continuous.is_some() {
                 println!("removing continuous");
@@ -0,0 +1,251 @@
+use crate::{
+    actor::{
+        player::{
+            Owner,
+            Player,
+            PlayerConfig,
+            MAX_PLAYERS,
+        },
+        ActorKind,
+        KillEvent,
+    },
+    global_input::{
+        toggle_active,
+        GlobalAction,
+    },
+    schedule::InGameSet,
+    state::{
+        GameState,
+        PlayingGame,
+    },
+};
+use bevy::prelude::*;
+use bevy_inspector_egui::{
+    inspector_options::std_options::NumberDisplay,
+    prelude::*,
+    quick::ResourceInspectorPlugin,
+};
+use bevy_rapier3d::{
+    geometry::Group,
+    prelude::CollisionGroups,
+};
+
+// every player gets a spaceship group and a weapon group of their own on top
+// of the shared ones - a player's weapons collide with everyone else's
+// spaceship group but never their own
+const PLAYER_SPACESHIP_GROUPS: [Group; MAX_PLAYERS] =
+    [Group::GROUP_7, Group::GROUP_8, Group::GROUP_9, Group::GROUP_10];
+const PLAYER_WEAPON_GROUPS: [Group; MAX_PLAYERS] =
+    [Group::GROUP_11, Group::GROUP_12, Group::GROUP_13, Group::GROUP_14];
+
+pub struct VersusPlugin;
+
+impl Plugin for VersusPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<VersusConfig>()
+            .init_resource::<VersusConfig>()
+            .init_resource::<VersusMatch>()
+            .add_plugins(
+                ResourceInspectorPlugin::<VersusConfig>::default()
+                    .run_if(toggle_active(false, GlobalAction::VersusInspector)),
+            )
+            .add_systems(OnEnter(PlayingGame), start_match)
+            .add_systems(OnEnter(GameState::GameOver), report_frags.run_if(versus_active))
+            // the victim's spaceship is still around until the DespawnEntities
+            // flush so we can still find out which player it belonged to
+            .add_systems(
+                Update,
+                count_frags
+                    .in_set(InGameSet::DespawnEntities)
+                    .run_if(versus_active),
+            )
+            .add_systems(
+                Update,
+                (assign_versus_collision_groups, end_match)
+                    .chain()
+                    .in_set(InGameSet::EntityUpdates)
+                    .run_if(versus_active),
+            );
+    }
+}
+
+// enabled takes effect the next time a game starts
+// nateroid_hazard keeps the waves of nateroids coming while the players fight
+// a frag_limit or time_limit_seconds of 0 means the match never ends that way
+#[derive(Resource, Reflect, InspectorOptions, Debug, Clone)]
+#[reflect(Resource, InspectorOptions)]
+pub struct VersusConfig {
+    pub enabled:            bool,
+    #[inspector(min = 0, max = 50, display = NumberDisplay::Slider)]
+    pub frag_limit:         u32,
+    pub nateroid_hazard:    bool,
+    #[inspector(min = 0.0, max = 600.0, display = NumberDisplay::Slider)]
+    pub time_limit_seconds: f32,
+}
+
+impl Default for VersusConfig {
+    fn default() -> Self {
+        Self {
+            enabled:            false,
+            frag_limit:         10,
+            nateroid_hazard:    true,
+            time_limit_seconds: 180.,
+        }
+    }
+}
+
+/// the match in progress - frags are indexed by Player
+#[derive(Resource, Debug, Default)]
+pub struct VersusMatch {
+    pub active:          bool,
+    pub frags:           Vec<u32>,
+    pub nateroid_hazard: bool,
+    frag_limit:          u32,
+    timer:               Option<Timer>,
+}
+
+impl VersusMatch {
+    /// `groups` for one of `player`'s weapons - unchanged outside of versus
+    pub fn weapon_collision_groups(&self, groups: CollisionGroups, player: Player) -> CollisionGroups {
+        if !self.active {
+            return groups;
+        }
+
+        CollisionGroups::new(
+            groups.memberships | PLAYER_WEAPON_GROUPS[player.0 % MAX_PLAYERS],
+            groups.filters | other_players(&PLAYER_SPACESHIP_GROUPS, player),
+        )
+    }
+
+    /// `groups` for `player`'s spaceship - unchanged outside of versus
+    pub fn spaceship_collision_groups(&self, groups: CollisionGroups, player: Player) -> CollisionGroups {
+        if !self.active {
+            return groups;
+        }
+
+        CollisionGroups::new(
+            groups.memberships | PLAYER_SPACESHIP_GROUPS[player.0 % MAX_PLAYERS],
+            groups.filters | other_players(&PLAYER_WEAPON_GROUPS, player),
+        )
+    }
+
+    fn leaders(&self) -> Vec<Player> {
+        let Some(&most_frags) = self.frags.iter().max() else {
+            return Vec::new();
+        };
+
+        self.frags
+            .iter()
+            .enumerate()
+            .filter(|&(_, &frags)| frags == most_frags)
+            .map(|(index, _)| Player(index))
+            .collect()
+    }
+}
+
+fn other_players(groups: &[Group; MAX_PLAYERS], player: Player) -> Group {
+    groups
+        .iter()
+        .enumerate()
+        .filter(|(index, _)| *index != player.0)
+        .fold(Group::NONE, |other_groups, (_, &group)| other_groups | group)
+}
+
+pub fn versus_active(versus_match: Res<VersusMatch>) -> bool { versus_match.active }
+
+/// waves keep running unless this is a versus match without the nateroid hazard
+pub fn nateroids_active(versus_match: Res<VersusMatch>) -> bool {
+    !versus_match.active || versus_match.nateroid_hazard
+}
+
+fn start_match(
+    mut versus_match: ResMut<VersusMatch>,
+    config: Res<VersusConfig>,
+    player_config: Res<PlayerConfig>,
+) {
+    let timer = (config.time_limit_seconds > 0.0)
+        .then(|| Timer::from_seconds(config.time_limit_seconds, TimerMode::Once));
+
+    *versus_match = VersusMatch {
+        active: config.enabled,
+        frags: vec![0; player_config.player_count()],
+        nateroid_hazard: config.nateroid_hazard,
+        frag_limit: config.frag_limit,
+        timer,
+    };
+}
+
+// spaceships and player weapons are spawned with the shared groups - they're
+// split up per player here as they show up. until then they only collide with
+// what they would outside of versus so nothing gets hit that shouldn't
+#[allow(clippy::type_complexity)]
+fn assign_versus_collision_groups(
+    mut q_spaceships: Query<(&Player, &mut CollisionGroups), Added<Player>>,
+    mut q_weapons: Query<(&Owner, &mut CollisionGroups), (Added<Owner>, Without<Player>)>,
+    versus_match: Res<VersusMatch>,
+) {
+    for (&player, mut groups) in q_spaceships.iter_mut() {
+        *groups = versus_match.spaceship_collision_groups(*groups, player);
+    }
+
+    for (owner, mut groups) in q_weapons.iter_mut() {
+        *groups = versus_match.weapon_collision_groups(*groups, owner.0);
+    }
+}
+
+// only a kill on somebody else's spaceship counts - crashing into a planet or a
+// nateroid doesn't cost you anything but the time it takes to respawn
+fn count_frags(
+    mut kill_events: EventReader<KillEvent>,
+    q_players: Query<&Player>,
+    mut versus_match: ResMut<VersusMatch>,
+) {
+    for kill in kill_events.read() {
+        if kill.victim_kind != ActorKind::Spaceship {
+            continue;
+        }
+
+        let (Some(killer), Ok(&victim)) = (kill.killer_player, q_players.get(kill.victim)) else {
+            continue;
+        };
+
+        if killer == victim {
+            continue;
+        }
+
+        if let Some(frags) = versus_match.frags.get_mut(killer.0) {
+            *frags += 1;
+            println!("player {} fragged player {} ({} frags)", killer.0 + 1, victim.0 + 1, frags);
+        }
+    }
+}
+
+fn end_match(
+    mut versus_match: ResMut<VersusMatch>,
+    mut next_state: ResMut<NextState<GameState>>,
+    time: Res<Time>,
+) {
+    let frag_limit = versus_match.frag_limit;
+    let frag_limit_reached = frag_limit > 0 && versus_match.frags.iter().any(|&frags| frags >= frag_limit);
+
+    let time_up = versus_match.timer.as_mut().is_some_and(|timer| {
+        timer.tick(time.delta());
+        timer.finished()
+    });
+
+    if frag_limit_reached || time_up {
+        println!("match over");
+        next_state.set(GameState::GameOver);
+    }
+}
+
+fn report_frags(versus_match: Res<VersusMatch>) {
+    for (index, frags) in versus_match.frags.iter().enumerate() {
+        println!("  player {}: {} frags", index + 1, frags);
+    }
+
+    match versus_match.leaders().as_slice() {
+        [winner] => println!("player {} wins", winner.0 + 1),
+        leaders => println!("draw between {} players", leaders.len()),
+    }
+}
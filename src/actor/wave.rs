@@ -0,0 +1,248 @@
+use crate::{
+    actor::{
+        actor_template::{
+            BossConfig,
+            NateroidConfig,
+        },
+        boss::spawn_boss,
+        nateroid::{
+            spawn_nateroid,
+            Nateroid,
+        },
+        versus::nateroids_active,
+    },
+    global_input::{
+        toggle_active,
+        GlobalAction,
+    },
+    orientation::CameraOrientation,
+    playfield::Boundary,
+    schedule::InGameSet,
+    state::PlayingGame,
+};
+use bevy::prelude::*;
+use bevy_inspector_egui::{
+    inspector_options::std_options::NumberDisplay,
+    prelude::*,
+    quick::ResourceInspectorPlugin,
+};
+
+pub struct WavePlugin;
+
+impl Plugin for WavePlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<WaveConfig>()
+            .init_resource::<WaveConfig>()
+            .init_resource::<Wave>()
+            .add_plugins(
+                ResourceInspectorPlugin::<WaveConfig>::default()
+                    .run_if(toggle_active(false, GlobalAction::WaveInspector)),
+            )
+            .add_systems(OnEnter(PlayingGame), reset_waves)
+            .add_systems(
+                Update,
+                direct_waves
+                    .in_set(InGameSet::EntityUpdates)
+                    .run_if(toggle_active(true, GlobalAction::SuppressNateroids))
+                    .run_if(nateroids_active),
+            );
+    }
+}
+
+#[derive(Reflect, Debug, Clone)]
+pub struct WaveLevel {
+    pub nateroid_count: u32,
+    pub spawn_interval: f32,
+}
+
+// levels are played in order - once we run out of them the last level keeps
+// getting harder: extra_nateroids_per_wave more nateroids each wave and a
+// spawn interval multiplied by spawn_interval_factor (never going below
+// min_spawn_interval)
+#[derive(Resource, Reflect, InspectorOptions, Debug, Clone)]
+#[reflect(Resource, InspectorOptions)]
+pub struct WaveConfig {
+    #[inspector(min = 0, max = 10, display = NumberDisplay::Slider)]
+    pub extra_nateroids_per_wave: u32,
+    #[inspector(min = 0.0, max = 10.0, display = NumberDisplay::Slider)]
+    pub intermission_seconds:     f32,
+    pub levels:                   Vec<WaveLevel>,
+    #[inspector(min = 0.05, max = 2.0, display = NumberDisplay::Slider)]
+    pub min_spawn_interval:       f32,
+    #[inspector(min = 0.5, max = 1.0, display = NumberDisplay::Slider)]
+    pub spawn_interval_factor:    f32,
+}
+
+impl Default for WaveConfig {
+    fn default() -> Self {
+        Self {
+            extra_nateroids_per_wave: 2,
+            intermission_seconds:     3.,
+            levels:                   vec![
+                WaveLevel {
+                    nateroid_count: 4,
+                    spawn_interval: 2.,
+                },
+                WaveLevel {
+                    nateroid_count: 6,
+                    spawn_interval: 1.5,
+                },
+                WaveLevel {
+                    nateroid_count: 8,
+                    spawn_interval: 1.2,
+                },
+                WaveLevel {
+                    nateroid_count: 10,
+                    spawn_interval: 1.,
+                },
+            ],
+            min_spawn_interval:       0.25,
+            spawn_interval_factor:    0.9,
+        }
+    }
+}
+
+impl WaveConfig {
+    /// waves are numbered from 1
+    pub fn level(&self, wave_number: u32) -> WaveLevel {
+        let index = wave_number.saturating_sub(1) as usize;
+
+        let Some(last) = self.levels.last() else {
+            return WaveLevel {
+                nateroid_count: 1 + self.extra_nateroids_per_wave * index as u32,
+                spawn_interval: self.min_spawn_interval,
+            };
+        };
+
+        if let Some(level) = self.levels.get(index) {
+            return level.clone();
+        }
+
+        let waves_past_last = (index + 1 - self.levels.len()) as u32;
+
+        WaveLevel {
+            nateroid_count: last.nateroid_count + self.extra_nateroids_per_wave * waves_past_last,
+            spawn_interval: (last.spawn_interval * self.spawn_interval_factor.powi(waves_past_last as i32))
+                .max(self.min_spawn_interval),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WavePhase {
+    // waiting between waves
+    Intermission,
+    // still has nateroids left to send in
+    Spawning,
+    // everything has been sent in - waiting on the player to clear the playfield
+    Clearing,
+}
+
+/// the director's state - number is the current wave and is 0 until the
+/// first intermission finishes
+#[derive(Resource, Debug)]
+pub struct Wave {
+    pub number:             u32,
+    pub phase:              WavePhase,
+    pub remaining_to_spawn: u32,
+    intermission_timer:     Timer,
+    spawn_timer:            Timer,
+}
+
+impl Default for Wave {
+    fn default() -> Self { Self::new(&WaveConfig::default()) }
+}
+
+impl Wave {
+    fn new(config: &WaveConfig) -> Self {
+        Self {
+            number:             0,
+            phase:              WavePhase::Intermission,
+            remaining_to_spawn: 0,
+            intermission_timer: Timer::from_seconds(config.intermission_seconds, TimerMode::Once),
+            spawn_timer:        Timer::from_seconds(config.min_spawn_interval, TimerMode::Repeating),
+        }
+    }
+
+    fn start_next_wave(&mut self, config: &WaveConfig) {
+        self.number += 1;
+        let level = config.level(self.number);
+
+        println!(
+            "wave {}: {} nateroids every {:.2}s",
+            self.number, level.nateroid_count, level.spawn_interval
+        );
+
+        self.phase = WavePhase::Spawning;
+        self.remaining_to_spawn = level.nateroid_count;
+        self.spawn_timer = Timer::from_seconds(level.spawn_interval, TimerMode::Repeating);
+    }
+
+    fn start_intermission(&mut self, config: &WaveConfig) {
+        self.phase = WavePhase::Intermission;
+        self.intermission_timer = Timer::from_seconds(config.intermission_seconds, TimerMode::Once);
+    }
+}
+
+fn reset_waves(mut wave: ResMut<Wave>, config: Res<WaveConfig>) { *wave = Wave::new(&config); }
+
+#[allow(clippy::too_many_arguments)]
+fn direct_waves(
+    mut commands: Commands,
+    mut wave: ResMut<Wave>,
+    wave_config: Res<WaveConfig>,
+    boss_config: Res<BossConfig>,
+    nateroid_config: Res<NateroidConfig>,
+    boundary: Res<Boundary>,
+    orientation: Res<CameraOrientation>,
+    q_nateroids: Query<(), With<Nateroid>>,
+    time: Res<Time>,
+) {
+    match wave.phase {
+        WavePhase::Intermission => {
+            wave.intermission_timer.tick(time.delta());
+            if !wave.intermission_timer.finished() {
+                return;
+            }
+
+            wave.start_next_wave(&wave_config);
+
+            // milestone waves open with a boss on top of the usual nateroids
+            if boss_config.wave_interval > 0 && wave.number.is_multiple_of(boss_config.wave_interval) {
+                spawn_boss(
+                    &mut commands,
+                    &boss_config,
+                    boundary,
+                    &orientation,
+                    time.elapsed_secs(),
+                );
+            }
+        },
+        WavePhase::Spawning => {
+            wave.spawn_timer.tick(time.delta());
+            if !wave.spawn_timer.just_finished() {
+                return;
+            }
+
+            spawn_nateroid(
+                &mut commands,
+                &nateroid_config,
+                boundary,
+                &orientation,
+                time.elapsed_secs(),
+            );
+
+            wave.remaining_to_spawn = wave.remaining_to_spawn.saturating_sub(1);
+            if wave.remaining_to_spawn == 0 {
+                wave.phase = WavePhase::Clearing;
+            }
+        },
+        WavePhase::Clearing => {
+            // fragments count - the wave isn't over until every last piece is gone
+            if q_nateroids.is_empty() {
+                println!("wave {} cleared", wave.number);
+                wave.start_intermission(&wave_config);
+            }
+        },
+    }
+}
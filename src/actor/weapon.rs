@@ -0,0 +1,741 @@
+use crate::{
+    actor::{
+        actor_spawner::{
+            spawn_actor,
+            ActorConfig,
+        },
+        actor_template::{
+            HomingConfig,
+            LaserConfig,
+            MissileConfig,
+            SpreadConfig,
+            TorpedoConfig,
+        },
+        collision_detection::{
+            can_be_scored,
+            deal_damage,
+            DamageableQuery,
+        },
+        missile::{
+            Homing,
+            Missile,
+        },
+        player::{
+            Owner,
+            Player,
+        },
+        spaceship::{
+            ContinuousFire,
+            Spaceship,
+        },
+        spaceship_control::SpaceshipControl,
+        versus::VersusMatch,
+        Aabb,
+        ActorKind,
+        DamageEvent,
+        KillEvent,
+    },
+    despawn::despawn,
+    global_input::{
+        toggle_active,
+        GlobalAction,
+    },
+    playfield::Boundary,
+    schedule::InGameSet,
+    state::PlayingGame,
+};
+use bevy::{
+    ecs::system::EntityCommands,
+    prelude::*,
+};
+use bevy_inspector_egui::{
+    inspector_options::std_options::NumberDisplay,
+    prelude::*,
+    quick::ResourceInspectorPlugin,
+};
+use bevy_rapier3d::prelude::*;
+use leafwing_input_manager::prelude::*;
+use strum::{
+    EnumIter,
+    IntoEnumIterator,
+};
+
+pub struct WeaponPlugin;
+
+impl Plugin for WeaponPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<WeaponLimitsConfig>()
+            .init_resource::<WeaponLimitsConfig>()
+            .add_event::<ShotFired>()
+            .add_event::<ShotHit>()
+            // shares the toggle with the rest of the weapon configs
+            .add_plugins(
+                ResourceInspectorPlugin::<WeaponLimitsConfig>::default()
+                    .run_if(toggle_active(false, GlobalAction::WeaponInspector)),
+            )
+            .add_systems(
+                Update,
+                (
+                    cycle_weapon,
+                    update_weapon_gauges,
+                    fire_missile,
+                    fire_spread,
+                    fire_laser,
+                    fire_torpedo,
+                    fire_homing,
+                )
+                    .chain()
+                    .in_set(InGameSet::UserInput),
+            )
+            .add_systems(Update, draw_laser_beams.in_set(InGameSet::EntityUpdates))
+            // beams have no health so they'd outlive the run
+            .add_systems(OnExit(PlayingGame), despawn_laser_beams);
+    }
+}
+
+// cycle_weapon steps through these in declaration order
+#[derive(Reflect, EnumIter, Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum WeaponKind {
+    #[default]
+    Missile,
+    Spread,
+    Laser,
+    Torpedo,
+    Homing,
+}
+
+impl WeaponKind {
+    pub fn index(self) -> usize { self as usize }
+
+    fn next(self) -> Self {
+        Self::iter()
+            .cycle()
+            .skip_while(|kind| *kind != self)
+            .nth(1)
+            .unwrap_or_default()
+    }
+}
+
+// heat runs from 0 to 1 - every shot adds heat_per_shot and once it reaches 1
+// the weapon is locked out until it has cooled back down to recovery_heat.
+// cooling_rate is how much heat is shed every second
+//
+// ammo_capacity is a full load - pickups put back refill_fraction of it
+#[derive(Reflect, InspectorOptions, Debug, Clone, Copy, PartialEq)]
+#[reflect(InspectorOptions)]
+pub struct WeaponLimits {
+    pub ammo_enabled:    bool,
+    #[inspector(min = 1, max = 500, display = NumberDisplay::Slider)]
+    pub ammo_capacity:   u32,
+    #[inspector(min = 0.0, max = 2.0, display = NumberDisplay::Slider)]
+    pub cooling_rate:    f32,
+    pub heat_enabled:    bool,
+    #[inspector(min = 0.0, max = 1.0, display = NumberDisplay::Slider)]
+    pub heat_per_shot:   f32,
+    #[inspector(min = 0.0, max = 1.0, display = NumberDisplay::Slider)]
+    pub recovery_heat:   f32,
+    #[inspector(min = 0.0, max = 1.0, display = NumberDisplay::Slider)]
+    pub refill_fraction: f32,
+}
+
+impl Default for WeaponLimits {
+    fn default() -> Self {
+        Self {
+            ammo_enabled:    false,
+            ammo_capacity:   100,
+            cooling_rate:    0.5,
+            heat_enabled:    false,
+            heat_per_shot:   0.05,
+            recovery_heat:   0.25,
+            refill_fraction: 0.5,
+        }
+    }
+}
+
+/// heat and ammo are both optional and set up separately for every weapon
+#[derive(Resource, Reflect, InspectorOptions, Debug, Clone, PartialEq)]
+#[reflect(Resource, InspectorOptions)]
+pub struct WeaponLimitsConfig {
+    pub homing:  WeaponLimits,
+    pub laser:   WeaponLimits,
+    pub missile: WeaponLimits,
+    pub spread:  WeaponLimits,
+    pub torpedo: WeaponLimits,
+}
+
+impl Default for WeaponLimitsConfig {
+    fn default() -> Self {
+        Self {
+            homing:  WeaponLimits {
+                ammo_capacity: 20,
+                ..default()
+            },
+            laser:   WeaponLimits {
+                heat_per_shot: 0.1,
+                ..default()
+            },
+            missile: WeaponLimits::default(),
+            spread:  WeaponLimits {
+                heat_per_shot: 0.15,
+                ..default()
+            },
+            torpedo: WeaponLimits {
+                ammo_capacity: 10,
+                ..default()
+            },
+        }
+    }
+}
+
+impl WeaponLimitsConfig {
+    pub fn limits(&self, kind: WeaponKind) -> &WeaponLimits {
+        match kind {
+            WeaponKind::Homing => &self.homing,
+            WeaponKind::Laser => &self.laser,
+            WeaponKind::Missile => &self.missile,
+            WeaponKind::Spread => &self.spread,
+            WeaponKind::Torpedo => &self.torpedo,
+        }
+    }
+}
+
+/// what a HUD needs to draw one weapon's gauges - ammo is None for a weapon
+/// that isn't using any
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct WeaponGauge {
+    pub ammo:       Option<u32>,
+    pub heat:       f32,
+    pub overheated: bool,
+}
+
+impl WeaponGauge {
+    pub fn locked_out(&self) -> bool { self.overheated || self.ammo == Some(0) }
+}
+
+/// the spaceship's currently selected weapon - last_fired is shared across
+/// weapons so switching can't be used to dodge the slower fire rates. there's
+/// a gauge for every weapon indexed by WeaponKind::index so the ones you
+/// aren't holding keep cooling down
+#[derive(Component, Debug)]
+pub struct Weapon {
+    pub kind:   WeaponKind,
+    gauges:     Vec<WeaponGauge>,
+    last_fired: f32,
+}
+
+impl Default for Weapon {
+    fn default() -> Self {
+        Self {
+            kind:       WeaponKind::default(),
+            gauges:     vec![WeaponGauge::default(); WeaponKind::iter().count()],
+            last_fired: f32::NEG_INFINITY,
+        }
+    }
+}
+
+impl Weapon {
+    pub fn gauge(&self, kind: WeaponKind) -> &WeaponGauge { &self.gauges[kind.index()] }
+
+    fn gauge_mut(&mut self, kind: WeaponKind) -> &mut WeaponGauge { &mut self.gauges[kind.index()] }
+
+    /// tops up every weapon that uses ammo by its refill_fraction
+    pub fn refill(&mut self, config: &WeaponLimitsConfig) {
+        for kind in WeaponKind::iter() {
+            let limits = config.limits(kind);
+            let refill = (limits.ammo_capacity as f32 * limits.refill_fraction).ceil() as u32;
+
+            if let Some(ammo) = self.gauge_mut(kind).ammo.as_mut() {
+                *ammo = (*ammo + refill).min(limits.ammo_capacity);
+            }
+        }
+    }
+}
+
+/// sent for every shot a player fires - each of the spread's projectiles is a
+/// shot of its own
+#[derive(Event, Debug, Clone, Copy)]
+pub struct ShotFired;
+
+/// sent when one of a player's shots lands on something that can be scored -
+/// at most once per shot, so there are never more hits than shots fired
+#[derive(Event, Debug, Clone, Copy)]
+pub struct ShotHit;
+
+#[derive(Component, Debug)]
+struct LaserBeam {
+    start: Vec3,
+    end:   Vec3,
+    timer: Timer,
+}
+
+type WeaponQuery<'w, 's> = Query<
+    'w,
+    's,
+    (
+        Entity,
+        &'static Player,
+        &'static ActionState<SpaceshipControl>,
+        &'static Transform,
+        &'static Velocity,
+        &'static Aabb,
+        &'static mut Weapon,
+        Option<&'static ContinuousFire>,
+    ),
+    With<Spaceship>,
+>;
+
+fn cycle_weapon(mut q_weapon: Query<(&ActionState<SpaceshipControl>, &mut Weapon), With<Spaceship>>) {
+    for (controls, mut weapon) in q_weapon.iter_mut() {
+        if controls.just_pressed(&SpaceshipControl::CycleWeapon) {
+            weapon.kind = weapon.kind.next();
+        }
+    }
+}
+
+// cooling happens every frame whether or not the weapon is selected. turning
+// ammo on in the inspector hands out a full load and turning it off takes it
+// away again
+fn update_weapon_gauges(
+    mut q_weapon: Query<&mut Weapon, With<Spaceship>>,
+    config: Res<WeaponLimitsConfig>,
+    time: Res<Time>,
+) {
+    let delta_seconds = time.delta_secs();
+
+    for mut weapon in q_weapon.iter_mut() {
+        for kind in WeaponKind::iter() {
+            let limits = config.limits(kind);
+            let gauge = weapon.gauge_mut(kind);
+
+            if limits.heat_enabled {
+                gauge.heat = (gauge.heat - limits.cooling_rate * delta_seconds).max(0.0);
+                if gauge.overheated && gauge.heat <= limits.recovery_heat {
+                    gauge.overheated = false;
+                }
+            } else {
+                gauge.heat = 0.0;
+                gauge.overheated = false;
+            }
+
+            gauge.ammo = match (limits.ammo_enabled, gauge.ammo) {
+                (true, Some(ammo)) => Some(ammo.min(limits.ammo_capacity)),
+                (true, None) => Some(limits.ammo_capacity),
+                (false, _) => None,
+            };
+        }
+    }
+}
+
+/// Logic to handle whether we're in continuous fire mode or just regular fire
+/// mode - either way the weapon's spawn_timer_seconds has to have passed since
+/// the last shot. if continuous we just need to be holding down the fire button
+///
+/// an overheated or empty weapon doesn't fire at all - a lockout doesn't start
+/// the timer so it's ready to go the moment the lockout ends
+fn should_fire(
+    kind: WeaponKind,
+    config: &ActorConfig,
+    limits: &WeaponLimits,
+    weapon: &mut Weapon,
+    continuous_fire: Option<&ContinuousFire>,
+    action_state: &ActionState<SpaceshipControl>,
+    now: f32,
+) -> bool {
+    if weapon.kind != kind || !config.spawnable || weapon.gauge(kind).locked_out() {
+        return false;
+    }
+
+    let fire_interval = config.spawn_timer_seconds.unwrap_or(0.0);
+    if now - weapon.last_fired < fire_interval {
+        return false;
+    }
+
+    let firing = if continuous_fire.is_some() {
+        action_state.pressed(&SpaceshipControl::Fire)
+    } else {
+        action_state.just_pressed(&SpaceshipControl::Fire)
+    };
+
+    if firing {
+        weapon.last_fired = now;
+
+        let gauge = weapon.gauge_mut(kind);
+        if limits.heat_enabled {
+            gauge.heat = (gauge.heat + limits.heat_per_shot).min(1.0);
+            gauge.overheated = gauge.heat >= 1.0;
+        }
+        if let Some(ammo) = gauge.ammo.as_mut() {
+            *ammo = ammo.saturating_sub(1);
+        }
+    }
+
+    firing
+}
+
+fn spawn_projectile<'a>(
+    commands: &'a mut Commands,
+    config: &ActorConfig,
+    boundary: &Boundary,
+    player: Player,
+    parent: (&Transform, &Velocity, &Aabb),
+) -> EntityCommands<'a> {
+    let mut projectile = spawn_actor(commands, config, None, Some(parent));
+    projectile
+        .insert(Missile::new(boundary.max_missile_distance()))
+        .insert(Owner(player));
+    projectile
+}
+
+fn fire_missile(
+    mut commands: Commands,
+    mut q_weapon: WeaponQuery,
+    mut shots_fired: EventWriter<ShotFired>,
+    boundary: Res<Boundary>,
+    missile_config: Res<MissileConfig>,
+    limits_config: Res<WeaponLimitsConfig>,
+    time: Res<Time>,
+) {
+    for (_, &player, controls, transform, velocity, aabb, mut weapon, continuous_fire) in
+        q_weapon.iter_mut()
+    {
+        if !should_fire(
+            WeaponKind::Missile,
+            &missile_config.0,
+            limits_config.limits(WeaponKind::Missile),
+            &mut weapon,
+            continuous_fire,
+            controls,
+            time.elapsed_secs(),
+        ) {
+            continue;
+        }
+
+        shots_fired.send(ShotFired);
+
+        spawn_projectile(
+            &mut commands,
+            &missile_config.0,
+            &boundary,
+            player,
+            (transform, velocity, aabb),
+        );
+    }
+}
+
+// the spaceship's up axis is the normal to the plane it flies in so fanning the
+// shots out around it keeps them on the playfield
+fn fire_spread(
+    mut commands: Commands,
+    mut q_weapon: WeaponQuery,
+    mut shots_fired: EventWriter<ShotFired>,
+    boundary: Res<Boundary>,
+    spread_config: Res<SpreadConfig>,
+    limits_config: Res<WeaponLimitsConfig>,
+    time: Res<Time>,
+) {
+    for (_, &player, controls, transform, velocity, aabb, mut weapon, continuous_fire) in
+        q_weapon.iter_mut()
+    {
+        if !should_fire(
+            WeaponKind::Spread,
+            &spread_config.actor,
+            limits_config.limits(WeaponKind::Spread),
+            &mut weapon,
+            continuous_fire,
+            controls,
+            time.elapsed_secs(),
+        ) {
+            continue;
+        }
+
+        let count = spread_config.projectile_count.max(1);
+        let step = if count > 1 {
+            spread_config.spread_angle / (count - 1) as f32
+        } else {
+            0.0
+        };
+        let first_angle = -step * (count - 1) as f32 / 2.0;
+
+        for i in 0..count {
+            let mut fanned_transform = *transform;
+            fanned_transform.rotate_axis(transform.up(), first_angle + step * i as f32);
+            shots_fired.send(ShotFired);
+
+            spawn_projectile(
+                &mut commands,
+                &spread_config.actor,
+                &boundary,
+                player,
+                (&fanned_transform, velocity, aabb),
+            );
+        }
+    }
+}
+
+// the laser hits instantly so rather than spawning anything that collides we
+// ray cast along the spaceship's heading and apply the damage ourselves -
+// through deal_damage so shields and invulnerability hold up against it too
+#[allow(clippy::too_many_arguments)]
+fn fire_laser(
+    mut commands: Commands,
+    mut q_weapon: WeaponQuery,
+    mut q_damageable: DamageableQuery,
+    q_kind: Query<&ActorKind>,
+    mut damage_events: EventWriter<DamageEvent>,
+    mut kill_events: EventWriter<KillEvent>,
+    mut shots_fired: EventWriter<ShotFired>,
+    mut shots_hit: EventWriter<ShotHit>,
+    laser_config: Res<LaserConfig>,
+    limits_config: Res<WeaponLimitsConfig>,
+    rapier_context: ReadDefaultRapierContext,
+    time: Res<Time>,
+    versus_match: Res<VersusMatch>,
+) {
+    for (entity, &player, controls, transform, _, _, mut weapon, continuous_fire) in q_weapon.iter_mut() {
+        if !should_fire(
+            WeaponKind::Laser,
+            &laser_config.actor,
+            limits_config.limits(WeaponKind::Laser),
+            &mut weapon,
+            continuous_fire,
+            controls,
+            time.elapsed_secs(),
+        ) {
+            continue;
+        }
+
+        shots_fired.send(ShotFired);
+
+        let start = transform.translation;
+        let direction = -transform.forward().as_vec3();
+
+        let groups = versus_match.weapon_collision_groups(laser_config.actor.collision_groups, player);
+        let filter = QueryFilter::new().groups(groups).exclude_collider(entity);
+
+        let hit = rapier_context
+            .single()
+            .cast_ray(start, direction, laser_config.range, true, filter);
+
+        let end = match hit {
+            Some((victim, distance)) => {
+                let end = start + direction * distance;
+
+                if let Some((damage, killing_blow)) =
+                    deal_damage(&mut q_damageable, victim, laser_config.actor.collision_damage)
+                {
+                    if q_kind.get(victim).is_ok_and(|&victim_kind| can_be_scored(victim_kind)) {
+                        shots_hit.send(ShotHit);
+                    }
+
+                    if damage > 0.0 {
+                        damage_events.send(DamageEvent {
+                            amount: damage,
+                            position: end,
+                            killing_blow,
+                        });
+                    }
+
+                    if let (true, Ok(&victim_kind)) = (killing_blow, q_kind.get(victim)) {
+                        kill_events.send(KillEvent {
+                            victim,
+                            victim_kind,
+                            killer_kind: laser_config.actor.actor_kind,
+                            killer_player: Some(player),
+                        });
+                    }
+                }
+                end
+            },
+            None => start + direction * laser_config.range,
+        };
+
+        commands.spawn(LaserBeam {
+            start,
+            end,
+            timer: Timer::from_seconds(laser_config.beam_duration, TimerMode::Once),
+        });
+    }
+}
+
+fn fire_torpedo(
+    mut commands: Commands,
+    mut q_weapon: WeaponQuery,
+    mut shots_fired: EventWriter<ShotFired>,
+    boundary: Res<Boundary>,
+    torpedo_config: Res<TorpedoConfig>,
+    limits_config: Res<WeaponLimitsConfig>,
+    time: Res<Time>,
+) {
+    for (_, &player, controls, transform, velocity, aabb, mut weapon, continuous_fire) in
+        q_weapon.iter_mut()
+    {
+        if !should_fire(
+            WeaponKind::Torpedo,
+            &torpedo_config.0,
+            limits_config.limits(WeaponKind::Torpedo),
+            &mut weapon,
+            continuous_fire,
+            controls,
+            time.elapsed_secs(),
+        ) {
+            continue;
+        }
+
+        shots_fired.send(ShotFired);
+
+        spawn_projectile(
+            &mut commands,
+            &torpedo_config.0,
+            &boundary,
+            player,
+            (transform, velocity, aabb),
+        );
+    }
+}
+
+fn fire_homing(
+    mut commands: Commands,
+    mut q_weapon: WeaponQuery,
+    mut shots_fired: EventWriter<ShotFired>,
+    boundary: Res<Boundary>,
+    homing_config: Res<HomingConfig>,
+    limits_config: Res<WeaponLimitsConfig>,
+    time: Res<Time>,
+) {
+    for (_, &player, controls, transform, velocity, aabb, mut weapon, continuous_fire) in
+        q_weapon.iter_mut()
+    {
+        if !should_fire(
+            WeaponKind::Homing,
+            &homing_config.actor,
+            limits_config.limits(WeaponKind::Homing),
+            &mut weapon,
+            continuous_fire,
+            controls,
+            time.elapsed_secs(),
+        ) {
+            continue;
+        }
+
+        shots_fired.send(ShotFired);
+
+        spawn_projectile(
+            &mut commands,
+            &homing_config.actor,
+            &boundary,
+            player,
+            (transform, velocity, aabb),
+        )
+        .insert(Homing);
+    }
+}
+
+fn draw_laser_beams(
+    mut commands: Commands,
+    mut q_beams: Query<(Entity, &mut LaserBeam)>,
+    laser_config: Res<LaserConfig>,
+    time: Res<Time>,
+    mut gizmos: Gizmos,
+) {
+    for (entity, mut beam) in q_beams.iter_mut() {
+        beam.timer.tick(time.delta());
+
+        if beam.timer.finished() {
+            commands.entity(entity).despawn();
+            continue;
+        }
+
+        let alpha = 1.0 - beam.timer.fraction();
+        gizmos.line(beam.start, beam.end, laser_config.beam_color.with_alpha(alpha));
+    }
+}
+
+fn despawn_laser_beams(mut commands: Commands, q_beams: Query<Entity, With<LaserBeam>>) {
+    for entity in q_beams.iter() {
+        despawn(&mut commands, entity);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fire_pressed() -> ActionState<SpaceshipControl> {
+        let mut action_state = ActionState::default();
+        action_state.press(&SpaceshipControl::Fire);
+        action_state
+    }
+
+    fn fire(weapon: &mut Weapon, limits: &WeaponLimits, now: f32) -> bool {
+        should_fire(
+            WeaponKind::Missile,
+            &ActorConfig::default(),
+            limits,
+            weapon,
+            None,
+            &fire_pressed(),
+            now,
+        )
+    }
+
+    #[test]
+    fn heat_builds_up_to_a_lockout() {
+        let limits = WeaponLimits {
+            heat_enabled:  true,
+            heat_per_shot: 0.5,
+            ..default()
+        };
+        let mut weapon = Weapon::default();
+
+        assert!(fire(&mut weapon, &limits, 0.));
+        assert!(!weapon.gauge(WeaponKind::Missile).locked_out());
+        assert!(fire(&mut weapon, &limits, 1.));
+        assert!(weapon.gauge(WeaponKind::Missile).overheated);
+        assert!(!fire(&mut weapon, &limits, 2.));
+    }
+
+    #[test]
+    fn heat_is_ignored_when_disabled() {
+        let limits = WeaponLimits {
+            heat_per_shot: 1.,
+            ..default()
+        };
+        let mut weapon = Weapon::default();
+
+        assert!(fire(&mut weapon, &limits, 0.));
+        assert!(fire(&mut weapon, &limits, 1.));
+        assert_eq!(weapon.gauge(WeaponKind::Missile).heat, 0.);
+    }
+
+    #[test]
+    fn empty_weapon_is_locked_out() {
+        let limits = WeaponLimits::default();
+        let mut weapon = Weapon::default();
+        weapon.gauge_mut(WeaponKind::Missile).ammo = Some(1);
+
+        assert!(fire(&mut weapon, &limits, 0.));
+        assert_eq!(weapon.gauge(WeaponKind::Missile).ammo, Some(0));
+        assert!(!fire(&mut weapon, &limits, 1.));
+    }
+
+    #[test]
+    fn lockout_does_not_start_the_fire_timer() {
+        let limits = WeaponLimits::default();
+        let mut weapon = Weapon::default();
+        weapon.gauge_mut(WeaponKind::Missile).overheated = true;
+
+        assert!(!fire(&mut weapon, &limits, 1.));
+        assert_eq!(weapon.last_fired, f32::NEG_INFINITY);
+    }
+
+    #[test]
+    fn only_the_selected_weapon_fires() {
+        let limits = WeaponLimits::default();
+        let mut weapon = Weapon {
+            kind: WeaponKind::Laser,
+            ..default()
+        };
+
+        assert!(!fire(&mut weapon, &limits, 0.));
+    }
+}
@@ -28,7 +28,9 @@ pub enum AssetsState {
 pub struct SceneAssets {
     pub missile:   Handle<Scene>,
     pub nateroid:  Handle<Scene>,
-    pub spaceship: Handle<Scene>, // pub sphere: Handle<Scene>,
+    pub planet:    Handle<Scene>,
+    pub saucer:    Handle<Scene>,
+    pub spaceship: Handle<Scene>,
 }
 
 pub fn load_assets(
@@ -39,6 +41,8 @@ pub fn load_assets(
     *scene_assets = SceneAssets {
         missile:   asset_server.load("models/Bullets Pickup.glb#Scene0"),
         nateroid:  asset_server.load("models/donut.glb#Scene0"),
+        planet:    asset_server.load("models/Planet.glb#Scene0"),
+        saucer:    asset_server.load("models/sphere.glb#Scene0"),
         spaceship: asset_server.load("models/Spaceship.glb#Scene0"),
     };
 }
@@ -52,6 +56,8 @@ pub fn check_asset_loading(
     let all_assets_loaded = [
         scene_assets.missile.id(),
         scene_assets.nateroid.id(),
+        scene_assets.planet.id(),
+        scene_assets.saucer.id(),
         scene_assets.spaceship.id(),
     ]
     .iter()
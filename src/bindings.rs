@@ -0,0 +1,613 @@
+use crate::{
+    actor::{
+        player::{
+            Player,
+            PlayerConfig,
+        },
+        ControlScheme,
+        SpaceshipControl,
+    },
+    camera::CameraControl,
+    global_input::GlobalAction,
+    state::MenuScreen,
+};
+use bevy::{
+    prelude::*,
+    reflect::{
+        DynamicEnum,
+        DynamicVariant,
+        FromReflect,
+        TypeInfo,
+        Typed,
+        VariantInfo,
+    },
+};
+use leafwing_input_manager::{
+    clashing_inputs::BasicInputs,
+    prelude::*,
+};
+use std::fmt;
+use strum::IntoEnumIterator;
+
+// next to wherever the game is run from - delete it to go back to the defaults
+#[cfg(not(target_arch = "wasm32"))]
+const BINDINGS_FILE: &str = "bindings.cfg";
+
+pub struct BindingsPlugin;
+
+impl Plugin for BindingsPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(InputBindings::load())
+            .init_resource::<BindingPage>()
+            .init_resource::<Rebinding>()
+            .add_systems(OnExit(MenuScreen::Bindings), stop_capturing)
+            // also runs on the first frame to pick up whatever was loaded
+            .add_systems(Update, apply_bindings.run_if(resource_changed::<InputBindings>));
+    }
+}
+
+/// anything the rebinding screen can capture - shift chords are built the same
+/// way as insert_shift_input so they compare equal to the ones in
+/// GlobalAction::global_input_map
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Binding {
+    Gamepad(GamepadButton),
+    Key(KeyCode),
+    Shift(KeyCode),
+}
+
+impl Binding {
+    fn inputs(self) -> Vec<Box<dyn Buttonlike>> {
+        match self {
+            Self::Gamepad(button) => vec![Box::new(button)],
+            Self::Key(key) => vec![Box::new(key)],
+            Self::Shift(key) => vec![
+                Box::new(ButtonlikeChord::new([KeyCode::ShiftLeft]).with(key)),
+                Box::new(ButtonlikeChord::new([KeyCode::ShiftRight]).with(key)),
+            ],
+        }
+    }
+
+    // the binding takes the place of whatever the action was bound to on the
+    // same device - rebinding a key leaves the gamepad button alone and the
+    // other way around
+    fn replace<A: Actionlike>(self, input_map: &mut InputMap<A>, action: A) {
+        if let Some(inputs) = input_map.get_buttonlike_mut(&action) {
+            inputs.retain(|input| !self.same_device(input.as_reflect()));
+        }
+
+        match self {
+            Self::Gamepad(button) => {
+                input_map.insert(action, button);
+            },
+            Self::Key(key) => {
+                input_map.insert(action, key);
+            },
+            Self::Shift(key) => {
+                input_map.insert_one_to_many(
+                    action,
+                    [
+                        ButtonlikeChord::new([KeyCode::ShiftLeft]).with(key),
+                        ButtonlikeChord::new([KeyCode::ShiftRight]).with(key),
+                    ],
+                );
+            },
+        }
+    }
+
+    // the shift chords are the only chords in the keyboard maps
+    fn same_device(self, input: &dyn Reflect) -> bool {
+        match self {
+            Self::Gamepad(_) => input.is::<GamepadButton>(),
+            Self::Key(_) | Self::Shift(_) => input.is::<KeyCode>() || input.is::<ButtonlikeChord>(),
+        }
+    }
+
+    // holding a shift chord holds its key too, so besides being the same input
+    // a shift chord and the plain key inside it clash in either direction
+    fn clashes_with(self, bound: &[Box<dyn Buttonlike>]) -> bool {
+        let inputs = self.inputs();
+
+        bound.iter().any(|input| {
+            inputs.contains(input)
+                || match (self, input.decompose()) {
+                    (Self::Key(_), BasicInputs::Chord(parts)) => {
+                        parts.iter().any(|part| inputs.contains(part))
+                    },
+                    (Self::Shift(key), BasicInputs::Simple(plain)) => {
+                        Self::Key(key).inputs().contains(&plain)
+                    },
+                    _ => false,
+                }
+        })
+    }
+
+    fn parse(text: &str) -> Option<Self> {
+        let (kind, name) = text.split_once(' ')?;
+
+        match kind {
+            "gamepad" => unit_variant(name).map(Self::Gamepad),
+            "key" => unit_variant(name).map(Self::Key),
+            "shift" => unit_variant(name).map(Self::Shift),
+            _ => None,
+        }
+    }
+}
+
+// the bindings file uses the Debug names of KeyCode and GamepadButton - reflection
+// gets us back from the name without having to list every key. from_reflect
+// panics on a variant that doesn't exist so the name is checked first
+fn unit_variant<T: FromReflect + Typed>(name: &str) -> Option<T> {
+    let TypeInfo::Enum(enum_info) = T::type_info() else {
+        return None;
+    };
+
+    if !matches!(enum_info.variant(name), Some(VariantInfo::Unit(_))) {
+        return None;
+    }
+
+    T::from_reflect(&DynamicEnum::new(name.to_string(), DynamicVariant::Unit))
+}
+
+impl fmt::Display for Binding {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Gamepad(button) => write!(f, "gamepad {:?}", button),
+            Self::Key(key) => write!(f, "key {:?}", key),
+            Self::Shift(key) => write!(f, "shift {:?}", key),
+        }
+    }
+}
+
+/// one action in one of the input maps - only the camera's button actions can
+/// be rebound, orbit, pan and zoom are mouse gestures
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BindingTarget {
+    Camera(CameraControl),
+    Global(GlobalAction),
+    Spaceship(ControlScheme, SpaceshipControl),
+}
+
+impl BindingTarget {
+    pub fn action_name(self) -> String {
+        match self {
+            Self::Camera(action) => format!("{:?}", action),
+            Self::Global(action) => format!("{:?}", action),
+            Self::Spaceship(_, action) => format!("{:?}", action),
+        }
+    }
+
+    fn map_name(self) -> &'static str {
+        match self {
+            Self::Camera(_) => "camera",
+            Self::Global(_) => "global",
+            Self::Spaceship(scheme, _) => scheme.name(),
+        }
+    }
+
+    // one player and the split keyboard are never in use at the same time so
+    // they're free to share keys - everything else is live together
+    fn overlaps(self, other: Self) -> bool {
+        match (self, other) {
+            (Self::Spaceship(scheme, _), Self::Spaceship(other_scheme, _)) => {
+                (scheme == ControlScheme::SinglePlayer) == (other_scheme == ControlScheme::SinglePlayer)
+            },
+            _ => true,
+        }
+    }
+
+    fn parse(text: &str) -> Option<Self> {
+        let (map_name, action_name) = text.split_once(' ')?;
+
+        Self::all().find(|target| target.map_name() == map_name && target.action_name() == action_name)
+    }
+
+    fn all() -> impl Iterator<Item = Self> { BindingPage::all().into_iter().flat_map(BindingPage::targets) }
+}
+
+impl fmt::Display for BindingTarget {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", self.map_name(), self.action_name())
+    }
+}
+
+/// rebinding is layered over the hardcoded maps - each rebound action keeps
+/// just the one binding it was given and everything else stays at its default
+#[derive(Resource, Debug, Default, Clone)]
+pub struct InputBindings {
+    overrides: Vec<(BindingTarget, Binding)>,
+}
+
+impl InputBindings {
+    pub fn camera_input_map(&self) -> InputMap<CameraControl> {
+        let mut input_map = CameraControl::camera_input_map();
+
+        for &(target, binding) in self.overrides.iter() {
+            if let BindingTarget::Camera(action) = target {
+                binding.replace(&mut input_map, action);
+            }
+        }
+
+        input_map
+    }
+
+    pub fn global_input_map(&self) -> InputMap<GlobalAction> {
+        let mut input_map = GlobalAction::global_input_map();
+
+        for &(target, binding) in self.overrides.iter() {
+            if let BindingTarget::Global(action) = target {
+                binding.replace(&mut input_map, action);
+            }
+        }
+
+        input_map
+    }
+
+    pub fn spaceship_input_map(&self, scheme: ControlScheme) -> InputMap<SpaceshipControl> {
+        let mut input_map = scheme.default_input_map();
+
+        for &(target, binding) in self.overrides.iter() {
+            if let BindingTarget::Spaceship(target_scheme, action) = target {
+                if target_scheme == scheme {
+                    binding.replace(&mut input_map, action);
+                }
+            }
+        }
+
+        input_map
+    }
+
+    fn bound_inputs(&self, target: BindingTarget) -> Vec<Box<dyn Buttonlike>> {
+        match target {
+            BindingTarget::Camera(action) => self.camera_input_map().get_buttonlike(&action).cloned(),
+            BindingTarget::Global(action) => self.global_input_map().get_buttonlike(&action).cloned(),
+            BindingTarget::Spaceship(scheme, action) => {
+                self.spaceship_input_map(scheme).get_buttonlike(&action).cloned()
+            },
+        }
+        .unwrap_or_default()
+    }
+
+    pub fn describe(&self, target: BindingTarget) -> String { describe_inputs(&self.bound_inputs(target)) }
+
+    /// the first other action whose inputs clash with this binding - the same
+    /// input, or a plain key that's also the key of a shift chord. pressing the
+    /// chord would fire the plain key's action along with it
+    pub fn conflict(&self, target: BindingTarget, binding: Binding) -> Option<BindingTarget> {
+        BindingTarget::all()
+            .filter(|&other| other != target && target.overlaps(other))
+            .find(|&other| binding.clashes_with(&self.bound_inputs(other)))
+    }
+
+    pub fn rebind(&mut self, target: BindingTarget, binding: Binding) {
+        self.overrides.retain(|(overridden, _)| *overridden != target);
+        self.overrides.push((target, binding));
+    }
+
+    pub fn reset(&mut self, page: BindingPage) {
+        let targets = page.targets();
+        self.overrides.retain(|(overridden, _)| !targets.contains(overridden));
+    }
+
+    fn parse_line(line: &str) -> Option<(BindingTarget, Binding)> {
+        let (target, binding) = line.split_once(" = ")?;
+        Some((BindingTarget::parse(target.trim())?, Binding::parse(binding.trim())?))
+    }
+
+    // a file we can't make sense of shouldn't keep the game from starting -
+    // anything unrecognized is skipped and the defaults fill in
+    #[cfg(not(target_arch = "wasm32"))]
+    fn load() -> Self {
+        let Ok(contents) = std::fs::read_to_string(BINDINGS_FILE) else {
+            return Self::default();
+        };
+
+        let mut bindings = Self::default();
+
+        for line in contents.lines().map(str::trim) {
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            match Self::parse_line(line) {
+                Some((target, binding)) => bindings.rebind(target, binding),
+                None => println!("skipping unrecognized binding in {}: {}", BINDINGS_FILE, line),
+            }
+        }
+
+        bindings
+    }
+
+    // no file system in the browser so bindings only last as long as the page
+    #[cfg(target_arch = "wasm32")]
+    fn load() -> Self { Self::default() }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn save(&self) {
+        let mut contents = String::from("# written by the rebinding screen - delete this file to reset\n");
+
+        for (target, binding) in self.overrides.iter() {
+            contents.push_str(&format!("{} = {}\n", target, binding));
+        }
+
+        if let Err(error) = std::fs::write(BINDINGS_FILE, contents) {
+            println!("couldn't save bindings to {}: {}", BINDINGS_FILE, error);
+        }
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    pub fn save(&self) {}
+}
+
+/// how the inputs bound to an action read on screen - both sides of a shift
+/// chord come out as the same "Shift+Key" so they're only listed once
+pub fn describe_inputs(inputs: &[Box<dyn Buttonlike>]) -> String {
+    let name = |input: &dyn Buttonlike| match format!("{:?}", input).as_str() {
+        "ShiftLeft" | "ShiftRight" => "Shift".to_string(),
+        name => name.to_string(),
+    };
+
+    let join_names = |inputs: &[Box<dyn Buttonlike>], separator: &str| {
+        inputs
+            .iter()
+            .map(|input| name(input.as_ref()))
+            .collect::<Vec<_>>()
+            .join(separator)
+    };
+
+    let mut descriptions: Vec<String> = Vec::new();
+
+    for input in inputs.iter() {
+        let description = match input.decompose() {
+            BasicInputs::None => continue,
+            BasicInputs::Simple(input) => name(input.as_ref()),
+            BasicInputs::Composite(inputs) => join_names(&inputs, "/"),
+            BasicInputs::Chord(inputs) => join_names(&inputs, "+"),
+        };
+
+        if !descriptions.contains(&description) {
+            descriptions.push(description);
+        }
+    }
+
+    descriptions.join(", ")
+}
+
+/// which map the rebinding screen is showing
+#[derive(Resource, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BindingPage {
+    Camera,
+    Global,
+    Spaceship(ControlScheme),
+}
+
+impl Default for BindingPage {
+    fn default() -> Self { Self::Spaceship(ControlScheme::SinglePlayer) }
+}
+
+impl BindingPage {
+    fn all() -> Vec<Self> {
+        ControlScheme::iter()
+            .map(Self::Spaceship)
+            .chain([Self::Camera, Self::Global])
+            .collect()
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Camera => "camera",
+            Self::Global => "global",
+            Self::Spaceship(scheme) => scheme.label(),
+        }
+    }
+
+    pub fn targets(self) -> Vec<BindingTarget> {
+        match self {
+            Self::Camera => CameraControl::iter()
+                .filter(|action| action.input_control_kind() == InputControlKind::Button)
+                .map(BindingTarget::Camera)
+                .collect(),
+            Self::Global => GlobalAction::iter().map(BindingTarget::Global).collect(),
+            Self::Spaceship(scheme) => SpaceshipControl::iter()
+                .map(|action| BindingTarget::Spaceship(scheme, action))
+                .collect(),
+        }
+    }
+
+    pub fn cycle(&mut self, direction: i32) {
+        let pages = Self::all();
+        let index = pages.iter().position(|page| page == self).unwrap_or(0) as i32;
+
+        *self = pages[(index + direction).rem_euclid(pages.len() as i32) as usize];
+    }
+}
+
+/// the action waiting on its next key or button press - armed is false for the
+/// frame the capture started in so the press that started it isn't captured
+#[derive(Resource, Debug, Default)]
+pub struct Rebinding {
+    armed:     bool,
+    capturing: Option<BindingTarget>,
+    message:   Option<String>,
+}
+
+impl Rebinding {
+    pub fn capture(&mut self, target: BindingTarget) {
+        *self = Self {
+            capturing: Some(target),
+            ..default()
+        };
+    }
+
+    pub fn is_capturing(&self) -> bool { self.capturing.is_some() }
+
+    pub fn label(&self, target: BindingTarget, bindings: &InputBindings) -> String {
+        let status = match (&self.message, self.capturing == Some(target)) {
+            (Some(message), true) => format!("{} - try another", message),
+            (None, true) => "press a key or button - escape cancels".to_string(),
+            (_, false) => bindings.describe(target),
+        };
+
+        format!("{}   {}", target.action_name(), status)
+    }
+}
+
+fn stop_capturing(mut rebinding: ResMut<Rebinding>) { *rebinding = Rebinding::default(); }
+
+// escape is left for cancelling so it can't be captured. holding shift turns a
+// key into a shift chord - shift on its own isn't something we bind
+pub(crate) fn capture_binding(
+    keys: Res<ButtonInput<KeyCode>>,
+    q_gamepads: Query<&Gamepad>,
+    mut bindings: ResMut<InputBindings>,
+    mut rebinding: ResMut<Rebinding>,
+) {
+    let Some(target) = rebinding.capturing else {
+        return;
+    };
+
+    if !rebinding.armed {
+        rebinding.armed = true;
+        return;
+    }
+
+    if keys.just_pressed(KeyCode::Escape) {
+        *rebinding = Rebinding::default();
+        return;
+    }
+
+    let shift = keys.any_pressed([KeyCode::ShiftLeft, KeyCode::ShiftRight]);
+
+    let key = keys
+        .get_just_pressed()
+        .find(|key| !matches!(key, KeyCode::ShiftLeft | KeyCode::ShiftRight))
+        .map(|&key| if shift { Binding::Shift(key) } else { Binding::Key(key) });
+
+    let button = q_gamepads
+        .iter()
+        .find_map(|gamepad| gamepad.get_just_pressed().next().copied())
+        .map(Binding::Gamepad);
+
+    let Some(binding) = key.or(button) else {
+        return;
+    };
+
+    match bindings.conflict(target, binding) {
+        Some(other) => rebinding.message = Some(format!("{} is already bound to {}", binding, other)),
+        None => {
+            bindings.rebind(target, binding);
+            bindings.save();
+            *rebinding = Rebinding::default();
+        },
+    }
+}
+
+// the global and camera maps are swapped out wholesale - a spaceship already
+// flying picks up its new map too, gamepad assignment and all
+fn apply_bindings(
+    bindings: Res<InputBindings>,
+    mut global_input_map: ResMut<InputMap<GlobalAction>>,
+    mut spaceship_input_map: ResMut<InputMap<SpaceshipControl>>,
+    mut q_camera: Query<&mut InputMap<CameraControl>>,
+    mut q_spaceships: Query<(&Player, &mut InputMap<SpaceshipControl>)>,
+    player_config: Res<PlayerConfig>,
+) {
+    *global_input_map = bindings.global_input_map();
+    *spaceship_input_map = bindings.spaceship_input_map(ControlScheme::SinglePlayer);
+
+    for mut input_map in q_camera.iter_mut() {
+        *input_map = bindings.camera_input_map();
+    }
+
+    for (&player, mut input_map) in q_spaceships.iter_mut() {
+        let gamepad = input_map.gamepad();
+        *input_map = bindings.spaceship_input_map(player_config.control_scheme(player));
+
+        if let Some(gamepad) = gamepad {
+            input_map.set_gamepad(gamepad);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn saved_lines_parse_back_to_the_same_binding() {
+        let overrides = [
+            (BindingTarget::Camera(CameraControl::Home), Binding::Key(KeyCode::F12)),
+            (BindingTarget::Global(GlobalAction::Stars), Binding::Shift(KeyCode::KeyO)),
+            (
+                BindingTarget::Spaceship(ControlScheme::Gamepad, SpaceshipControl::Fire),
+                Binding::Gamepad(GamepadButton::South),
+            ),
+            (
+                BindingTarget::Spaceship(ControlScheme::RightKeyboard, SpaceshipControl::Shield),
+                Binding::Key(KeyCode::KeyU),
+            ),
+        ];
+
+        for (target, binding) in overrides {
+            let line = format!("{} = {}", target, binding);
+
+            assert_eq!(InputBindings::parse_line(&line), Some((target, binding)), "{}", line);
+        }
+    }
+
+    #[test]
+    fn unrecognized_lines_are_rejected() {
+        for line in [
+            "global Stars = key NotAKey",
+            "global NotAnAction = key F3",
+            "nowhere Stars = key F3",
+            "global Stars = mouse Left",
+            "global Stars",
+        ] {
+            assert_eq!(InputBindings::parse_line(line), None, "{}", line);
+        }
+    }
+
+    #[test]
+    fn conflict_with_the_same_input() {
+        let bindings = InputBindings::default();
+
+        assert_eq!(
+            bindings.conflict(BindingTarget::Global(GlobalAction::AABBs), Binding::Key(KeyCode::F3)),
+            Some(BindingTarget::Global(GlobalAction::Stars))
+        );
+        assert_eq!(
+            bindings.conflict(BindingTarget::Global(GlobalAction::Stars), Binding::Key(KeyCode::F3)),
+            None
+        );
+    }
+
+    #[test]
+    fn conflict_between_a_plain_key_and_a_shift_chord_on_it() {
+        let bindings = InputBindings::default();
+
+        assert_eq!(
+            bindings.conflict(BindingTarget::Global(GlobalAction::Stars), Binding::Key(KeyCode::KeyY)),
+            Some(BindingTarget::Global(GlobalAction::HyperspaceInspector))
+        );
+        assert_eq!(
+            bindings.conflict(BindingTarget::Global(GlobalAction::AABBs), Binding::Shift(KeyCode::F3)),
+            Some(BindingTarget::Global(GlobalAction::Stars))
+        );
+    }
+
+    #[test]
+    fn single_player_and_split_keyboard_can_share_keys() {
+        let mut bindings = InputBindings::default();
+        let single_player = BindingTarget::Spaceship(ControlScheme::SinglePlayer, SpaceshipControl::Fire);
+        let left_keyboard = BindingTarget::Spaceship(ControlScheme::LeftKeyboard, SpaceshipControl::Fire);
+
+        bindings.rebind(single_player, Binding::Key(KeyCode::KeyU));
+
+        assert_eq!(bindings.conflict(left_keyboard, Binding::Key(KeyCode::KeyU)), None);
+        assert_eq!(
+            bindings.conflict(BindingTarget::Global(GlobalAction::Stars), Binding::Key(KeyCode::KeyU)),
+            Some(single_player)
+        );
+    }
+}
@@ -1,5 +1,8 @@
 use crate::{
-    camera::PrimaryCamera,
+    camera::{
+        chase_camera::ChaseCamera,
+        PrimaryCamera,
+    },
     global_input::{
         toggle_active,
         GlobalAction,
@@ -76,6 +79,12 @@ pub struct CameraConfig {
     pub bloom_high_pass_frequency: f32,
     #[inspector(min = 0.0, max = 1.0, display = NumberDisplay::Slider)]
     pub orbit_speed:               f32,
+    #[inspector(min = 0.0, max = 5.0, display = NumberDisplay::Slider)]
+    pub orientation_transition:    f32,
+    #[inspector(min = 1.0, max = 200.0, display = NumberDisplay::Slider)]
+    pub chase_stiffness:           f32,
+    #[inspector(min = 0.5, max = 20.0, display = NumberDisplay::Slider)]
+    pub chase_rotation_stiffness:  f32,
     #[inspector(min = 10.0, max = 200.0, display = NumberDisplay::Slider)]
     pub zoom_sensitivity_pinch:    f32,
     #[inspector(min = 1.0, max = 20.0, display = NumberDisplay::Slider)]
@@ -91,6 +100,9 @@ impl Default for CameraConfig {
             bloom_low_frequency_boost: 0.5,
             bloom_high_pass_frequency: 0.5,
             orbit_speed:               0.01,
+            orientation_transition:    1.5,
+            chase_stiffness:           40.,
+            chase_rotation_stiffness:  6.,
             zoom_sensitivity_pinch:    100.,
             zoom_sensitivity_mouse:    5.,
         }
@@ -103,6 +115,7 @@ impl Default for CameraConfig {
 //       Camera it might be something blender fans would like
 #[derive(Clone, Debug, EnumIter, Copy, PartialEq, Eq, Hash, Reflect)]
 pub enum CameraControl {
+    Chase,
     Home,
     Orbit,
     Pan,
@@ -112,6 +125,7 @@ pub enum CameraControl {
 impl Actionlike for CameraControl {
     fn input_control_kind(&self) -> InputControlKind {
         match self {
+            CameraControl::Chase => InputControlKind::Button,
             CameraControl::Home => InputControlKind::Button,
             CameraControl::Orbit => InputControlKind::DualAxis,
             CameraControl::Pan => InputControlKind::DualAxis,
@@ -123,6 +137,7 @@ impl Actionlike for CameraControl {
 impl CameraControl {
     pub fn camera_input_map() -> InputMap<Self> {
         Self::iter().fold(InputMap::default(), |input_map, action| match action {
+            Self::Chase => input_map.with(action, KeyCode::F5),
             Self::Home => input_map.with_one_to_many(action, [KeyCode::Home, KeyCode::F12]),
             Self::Orbit => input_map
                 .with_dual_axis(
@@ -180,9 +195,13 @@ fn check_inspector_state(
     }
 }
 
+// the chase camera drives the transform itself while it's on so homing, zooming,
+// panning and orbiting all leave it alone
+type FreeCamera = (With<PrimaryCamera>, Without<ChaseCamera>);
+
 fn home_camera(
     orientation: Res<CameraOrientation>,
-    mut camera_transform: Query<(&mut Transform, &ActionState<CameraControl>), With<PrimaryCamera>>,
+    mut camera_transform: Query<(&mut Transform, &ActionState<CameraControl>), FreeCamera>,
 ) {
     if let Ok((mut transform, action_state)) = camera_transform.get_single_mut() {
         if action_state.just_pressed(&CameraControl::Home) {
@@ -204,7 +223,7 @@ fn pinch_to_zoom(
 }
 
 fn zoom_camera(
-    mut query: Query<(&mut Transform, &mut ActionState<CameraControl>), With<PrimaryCamera>>,
+    mut query: Query<(&mut Transform, &mut ActionState<CameraControl>), FreeCamera>,
     mut mouse_wheel_events: EventReader<MouseWheel>,
     config: Res<CameraConfig>,
 ) {
@@ -277,7 +296,7 @@ fn should_zoom(
 }
 
 fn pan_camera(
-    mut query: Query<(&mut Transform, &ActionState<CameraControl>), With<PrimaryCamera>>,
+    mut query: Query<(&mut Transform, &ActionState<CameraControl>), FreeCamera>,
     keycode: Res<ButtonInput<KeyCode>>,
     orientation: Res<CameraOrientation>,
 ) {
@@ -322,7 +341,7 @@ fn should_pan(keycode: Res<ButtonInput<KeyCode>>, action_state: &ActionState<Cam
 // i couldn't get this to work without hitting gimbal lock when consulting with
 // chatGPT 4.o claude Sonnet 3.5 got it right on the first try - holy shit!
 fn orbit_camera(
-    mut q_camera: Query<(&mut Transform, &mut ActionState<CameraControl>), With<PrimaryCamera>>,
+    mut q_camera: Query<(&mut Transform, &mut ActionState<CameraControl>), FreeCamera>,
     camera_config: Res<CameraConfig>,
     keycode: Res<ButtonInput<KeyCode>>,
     orientation: Res<CameraOrientation>,
@@ -4,11 +4,15 @@ use crate::{
             CameraConfig,
             CameraControl,
         },
+        chase_camera::ChaseCamera,
         CameraOrder,
         RenderLayer,
     },
     global_input::GlobalAction,
-    orientation::CameraOrientation,
+    orientation::{
+        CameraOrientation,
+        OrientationType,
+    },
     playfield::Boundary,
 };
 use bevy::{
@@ -28,7 +32,8 @@ impl Plugin for CamerasPlugin {
         app.add_systems(Startup, spawn_star_camera.before(spawn_primary_camera))
             .add_systems(Startup, spawn_primary_camera)
             .add_systems(Update, update_clear_color)
-            .add_systems(Update, (toggle_stars, update_bloom_settings));
+            .add_systems(Update, (toggle_stars, update_bloom_settings))
+            .add_systems(Update, (start_locus_transition, transition_to_locus).chain());
     }
 }
 
@@ -116,8 +121,7 @@ pub fn spawn_primary_camera(
         .get_single_mut()
         .expect("why in god's name is there no star's camera?");
 
-      let transform = Transform::from_xyz(0.0, 0.0, config.scale().z * 2.)
-        .looking_at(orientation.config.nexus, orientation.config.axis_mundi);
+    let transform = orientation.locus(config.scale());
 
     orientation.config.locus = transform;
 
@@ -147,3 +151,60 @@ fn update_clear_color(camera_config: Res<CameraConfig>, mut clear_color: ResMut<
         clear_color.0 = camera_config.clear_color.darker(camera_config.darkening_factor);
     }
 }
+
+// the camera glides from wherever it was to the new locus when the orientation
+// changes rather than cutting straight there
+#[derive(Component, Debug)]
+pub struct LocusTransition {
+    from:  Transform,
+    timer: Timer,
+}
+
+// the chase camera already follows the spaceship around whatever the
+// orientation so it's left alone
+#[allow(clippy::type_complexity)]
+fn start_locus_transition(
+    mut commands: Commands,
+    q_camera: Query<(Entity, &Transform), (With<PrimaryCamera>, Without<ChaseCamera>)>,
+    camera_config: Res<CameraConfig>,
+    orientation: Res<CameraOrientation>,
+    mut last_orientation: Local<Option<OrientationType>>,
+) {
+    let previous_orientation = last_orientation.replace(orientation.orientation);
+
+    if previous_orientation.is_none_or(|previous| previous == orientation.orientation) {
+        return;
+    }
+
+    if let Ok((entity, transform)) = q_camera.get_single() {
+        commands.entity(entity).insert(LocusTransition {
+            from:  *transform,
+            timer: Timer::from_seconds(camera_config.orientation_transition, TimerMode::Once),
+        });
+    }
+}
+
+fn transition_to_locus(
+    mut commands: Commands,
+    mut q_camera: Query<(Entity, &mut Transform, &mut LocusTransition), With<PrimaryCamera>>,
+    orientation: Res<CameraOrientation>,
+    time: Res<Time>,
+) {
+    let Ok((entity, mut transform, mut transition)) = q_camera.get_single_mut() else {
+        return;
+    };
+
+    transition.timer.tick(time.delta());
+
+    // smoothstep so it eases out of the old view and settles into the new one
+    let t = transition.timer.fraction();
+    let eased = t * t * (3.0 - 2.0 * t);
+    let locus = orientation.config.locus;
+
+    transform.translation = transition.from.translation.lerp(locus.translation, eased);
+    transform.rotation = transition.from.rotation.slerp(locus.rotation, eased);
+
+    if transition.timer.finished() {
+        commands.entity(entity).remove::<LocusTransition>();
+    }
+}
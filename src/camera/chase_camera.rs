@@ -0,0 +1,126 @@
+use crate::{
+    actor::{
+        player::Player,
+        Spaceship,
+    },
+    camera::{
+        camera_control::{
+            CameraConfig,
+            CameraControl,
+        },
+        PrimaryCamera,
+    },
+    orientation::CameraOrientation,
+    playfield::Boundary,
+    state::PlayingGame,
+};
+use bevy::prelude::*;
+use leafwing_input_manager::prelude::*;
+
+pub struct ChaseCameraPlugin;
+
+impl Plugin for ChaseCameraPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Update, (toggle_chase_camera, chase_spaceship).chain())
+            .add_systems(OnExit(PlayingGame), stop_chasing);
+    }
+}
+
+// while this is on the PrimaryCamera it chases the spaceship instead of
+// orbiting the nexus - velocity is the spring's, not the spaceship's
+#[derive(Component, Debug, Default)]
+pub struct ChaseCamera {
+    last_spaceship_position: Option<Vec3>,
+    velocity:                Vec3,
+}
+
+// turning the chase camera off puts us right back at the locus - same as
+// CameraControl::Home
+#[allow(clippy::type_complexity)]
+fn toggle_chase_camera(
+    mut commands: Commands,
+    mut q_camera: Query<
+        (Entity, &mut Transform, &ActionState<CameraControl>, Option<&ChaseCamera>),
+        With<PrimaryCamera>,
+    >,
+    orientation: Res<CameraOrientation>,
+) {
+    let Ok((entity, mut transform, action_state, chase_camera)) = q_camera.get_single_mut() else {
+        return;
+    };
+
+    if !action_state.just_pressed(&CameraControl::Chase) {
+        return;
+    }
+
+    if chase_camera.is_some() {
+        commands.entity(entity).remove::<ChaseCamera>();
+        *transform = orientation.config.locus;
+    } else {
+        commands.entity(entity).insert(ChaseCamera::default());
+    }
+}
+
+// the next run starts from the locus with the camera controls back in charge
+fn stop_chasing(
+    mut commands: Commands,
+    mut q_camera: Query<(Entity, &mut Transform), With<ChaseCamera>>,
+    orientation: Res<CameraOrientation>,
+) {
+    if let Ok((entity, mut transform)) = q_camera.get_single_mut() {
+        commands.entity(entity).remove::<ChaseCamera>();
+        *transform = orientation.config.locus;
+    }
+}
+
+// with more than one player we follow the lowest numbered one still flying.
+// spaceship_offset is in the spaceship's own frame - up is its local y and it
+// heads toward local z so a negative z puts us behind it
+#[allow(clippy::type_complexity)]
+fn chase_spaceship(
+    mut q_camera: Query<(&mut Transform, &mut ChaseCamera), With<PrimaryCamera>>,
+    q_spaceships: Query<(&Transform, &Player), (With<Spaceship>, Without<PrimaryCamera>)>,
+    boundary: Res<Boundary>,
+    camera_config: Res<CameraConfig>,
+    orientation: Res<CameraOrientation>,
+    time: Res<Time>,
+) {
+    let Ok((mut camera_transform, mut chase_camera)) = q_camera.get_single_mut() else {
+        return;
+    };
+
+    let Some((spaceship_transform, _)) = q_spaceships.iter().min_by_key(|(_, player)| player.0) else {
+        return;
+    };
+
+    let spaceship_position = spaceship_transform.translation;
+
+    // when the spaceship teleports across the boundary carry the camera across
+    // by the same amount so it doesn't swoop over the whole playfield to catch up
+    if let Some(last_position) = chase_camera.last_spaceship_position {
+        let moved = spaceship_position - last_position;
+        camera_transform.translation += moved - boundary.wrapped_delta(last_position, spaceship_position);
+    }
+    chase_camera.last_spaceship_position = Some(spaceship_position);
+
+    let offset = orientation.config.spaceship_offset * spaceship_transform.scale;
+    let target_position = spaceship_position + spaceship_transform.rotation * offset;
+    let target_rotation = Transform::from_translation(target_position)
+        .looking_at(spaceship_position, spaceship_transform.up())
+        .rotation;
+
+    let delta_seconds = time.delta_secs();
+
+    // critically damped spring - it closes in as fast as it can without
+    // overshooting and bouncing around behind the spaceship
+    let stiffness = camera_config.chase_stiffness;
+    let damping = 2.0 * stiffness.sqrt();
+    let acceleration =
+        (target_position - camera_transform.translation) * stiffness - chase_camera.velocity * damping;
+
+    chase_camera.velocity += acceleration * delta_seconds;
+    camera_transform.translation += chase_camera.velocity * delta_seconds;
+
+    let rotation_blend = 1.0 - (-camera_config.chase_rotation_stiffness * delta_seconds).exp();
+    camera_transform.rotation = camera_transform.rotation.slerp(target_rotation, rotation_blend);
+}
@@ -167,9 +167,9 @@ fn manage_lighting(
     mut ambient_light: ResMut<AmbientLight>,
     light_config: Res<LightConfig>,
     camera_orientation: Res<CameraOrientation>,
-    mut query: Query<(Entity, &mut DirectionalLight, &LightDirection)>,
+    mut query: Query<(Entity, &mut DirectionalLight, &mut Transform, &LightDirection)>,
 ) {
-    if !light_config.is_changed() {
+    if !light_config.is_changed() && !camera_orientation.is_changed() {
         return;
     }
 
@@ -193,18 +193,19 @@ fn manage_lighting(
 
         // we always spawn a light with its current LightDirection - see
         // if we have the current loop's position in an already spawned entity
-        let existing_light = query.iter_mut().find(|(_, _, dir)| dir.0 == *position);
+        let existing_light = query.iter_mut().find(|(_, _, _, dir)| dir.0 == *position);
 
         let light_rotation = position.get_rotation(&camera_orientation);
 
         match (existing_light, settings.enabled) {
-            (Some((_, mut light, _)), true) => {
-                // Update existing light
+            (Some((_, mut light, mut transform, _)), true) => {
+                // Update existing light - the orientation may have changed under it
                 light.color = settings.color;
                 light.illuminance = settings.illuminance;
                 light.shadows_enabled = settings.shadows_enabled;
+                transform.rotation = Quat::from_axis_angle(light_rotation.axis, light_rotation.angle);
             },
-            (Some((entity, _, _)), false) => {
+            (Some((entity, _, _, _)), false) => {
                 // Remove disabled light
                 commands.entity(entity).despawn();
             },
@@ -1,4 +1,5 @@
 mod camera_control;
+mod chase_camera;
 mod cameras;
 mod lights;
 mod star_twinkling;
@@ -9,11 +10,18 @@ use bevy::{
     render::view::Layer,
 };
 
+pub use camera_control::{
+    CameraConfig,
+    CameraControl,
+};
 use camera_control::CameraControlPlugin;
 use cameras::CamerasPlugin;
+use chase_camera::ChaseCameraPlugin;
 pub use cameras::PrimaryCamera;
+pub use lights::LightConfig;
 use lights::DirectionalLightsPlugin;
 use star_twinkling::StarTwinklingPlugin;
+pub use stars::StarConfig;
 use stars::StarsPlugin;
 
 pub struct CameraPlugin;
@@ -21,6 +29,7 @@ pub struct CameraPlugin;
 impl Plugin for CameraPlugin {
     fn build(&self, app: &mut App) {
         app.add_plugins(CameraControlPlugin)
+            .add_plugins(ChaseCameraPlugin)
             .add_plugins(DirectionalLightsPlugin)
             .add_plugins(CamerasPlugin)
             .add_plugins(StarsPlugin)
@@ -0,0 +1,221 @@
+use crate::{
+    actor::DamageEvent,
+    camera::{
+        PrimaryCamera,
+        RenderLayer,
+    },
+    despawn::despawn,
+    global_input::{
+        toggle_active,
+        GlobalAction,
+    },
+    schedule::InGameSet,
+    state::PlayingGame,
+};
+use bevy::{
+    color::palettes::tailwind,
+    prelude::*,
+    render::view::RenderLayers,
+};
+use bevy_inspector_egui::{
+    inspector_options::std_options::NumberDisplay,
+    prelude::*,
+    quick::ResourceInspectorPlugin,
+};
+
+// wide enough for any number we'll show - the text is centered in it so the
+// number sits right over the hit
+const DAMAGE_NUMBER_WIDTH: f32 = 120.;
+
+pub struct DamageNumbersPlugin;
+
+impl Plugin for DamageNumbersPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<DamageNumberConfig>()
+            .init_resource::<DamageNumberConfig>()
+            .add_plugins(
+                ResourceInspectorPlugin::<DamageNumberConfig>::default()
+                    .run_if(toggle_active(false, GlobalAction::DamageNumbersInspector)),
+            )
+            .add_systems(OnExit(PlayingGame), despawn_damage_numbers)
+            .add_systems(
+                Update,
+                (
+                    spawn_damage_numbers.run_if(toggle_active(true, GlobalAction::DamageNumbers)),
+                    float_damage_numbers,
+                )
+                    .chain()
+                    .in_set(InGameSet::EntityUpdates),
+            )
+            .add_systems(Update, draw_hit_markers.run_if(in_state(PlayingGame)));
+    }
+}
+
+// each hit shows the damage dealt where it landed, drifting up the screen and
+// fading out over lifetime_seconds. killing blows get their own color and a
+// bigger number. the hit marker is a little x at the contact point that only
+// lasts for marker_seconds
+#[derive(Resource, Reflect, InspectorOptions, Debug, Clone)]
+#[reflect(Resource, InspectorOptions)]
+pub struct DamageNumberConfig {
+    pub color:            Color,
+    #[inspector(min = 0.0, max = 40.0, display = NumberDisplay::Slider)]
+    pub drift_speed:      f32,
+    #[inspector(min = 8.0, max = 64.0, display = NumberDisplay::Slider)]
+    pub font_size:        f32,
+    pub kill_color:       Color,
+    #[inspector(min = 8.0, max = 96.0, display = NumberDisplay::Slider)]
+    pub kill_font_size:   f32,
+    #[inspector(min = 0.1, max = 5.0, display = NumberDisplay::Slider)]
+    pub lifetime_seconds: f32,
+    #[inspector(min = 0.0, max = 2.0, display = NumberDisplay::Slider)]
+    pub marker_seconds:   f32,
+    #[inspector(min = 0.1, max = 10.0, display = NumberDisplay::Slider)]
+    pub marker_size:      f32,
+}
+
+impl Default for DamageNumberConfig {
+    fn default() -> Self {
+        Self {
+            color:            Color::from(tailwind::AMBER_200),
+            drift_speed:      8.,
+            font_size:        18.,
+            kill_color:       Color::from(tailwind::RED_500),
+            kill_font_size:   28.,
+            lifetime_seconds: 1.,
+            marker_seconds:   0.25,
+            marker_size:      1.5,
+        }
+    }
+}
+
+impl DamageNumberConfig {
+    fn color(&self, killing_blow: bool) -> Color {
+        if killing_blow {
+            self.kill_color
+        } else {
+            self.color
+        }
+    }
+}
+
+// contact is where the hit landed and stays put for the marker - position is
+// where the number has drifted to
+#[derive(Component, Debug)]
+struct DamageNumber {
+    contact:      Vec3,
+    killing_blow: bool,
+    position:     Vec3,
+    timer:        Timer,
+}
+
+fn spawn_damage_numbers(
+    mut commands: Commands,
+    mut damage_events: EventReader<DamageEvent>,
+    config: Res<DamageNumberConfig>,
+) {
+    for damage in damage_events.read() {
+        let (text, font_size) = if damage.killing_blow {
+            (format!("{:.0}!", damage.amount), config.kill_font_size)
+        } else {
+            (format!("{:.0}", damage.amount), config.font_size)
+        };
+
+        commands.spawn((
+            DamageNumber {
+                contact:      damage.position,
+                killing_blow: damage.killing_blow,
+                position:     damage.position,
+                timer:        Timer::from_seconds(config.lifetime_seconds, TimerMode::Once),
+            },
+            Text::new(text),
+            TextFont {
+                font_size,
+                ..default()
+            },
+            TextColor(config.color(damage.killing_blow)),
+            TextLayout::new_with_justify(JustifyText::Center),
+            Node {
+                width: Val::Px(DAMAGE_NUMBER_WIDTH),
+                position_type: PositionType::Absolute,
+                ..default()
+            },
+            // placed by float_damage_numbers once it knows where the camera is
+            Visibility::Hidden,
+            RenderLayers::from_layers(RenderLayer::Game.layers()),
+            Name::new("DamageNumber"),
+        ));
+    }
+}
+
+// the numbers live in the world but are drawn as ui so they always face the
+// camera - each frame they drift along the camera's up and get projected back
+// onto the screen. anything behind the camera is hidden
+fn float_damage_numbers(
+    mut commands: Commands,
+    mut q_numbers: Query<(Entity, &mut DamageNumber, &mut Node, &mut TextColor, &mut Visibility)>,
+    q_camera: Query<(&Camera, &GlobalTransform), With<PrimaryCamera>>,
+    config: Res<DamageNumberConfig>,
+    time: Res<Time>,
+) {
+    let Ok((camera, camera_transform)) = q_camera.get_single() else {
+        return;
+    };
+
+    let drift = camera_transform.up().as_vec3() * config.drift_speed * time.delta_secs();
+
+    for (entity, mut number, mut node, mut text_color, mut visibility) in q_numbers.iter_mut() {
+        number.timer.tick(time.delta());
+
+        if number.timer.finished() {
+            despawn(&mut commands, entity);
+            continue;
+        }
+
+        number.position += drift;
+
+        let Ok(screen) = camera.world_to_viewport(camera_transform, number.position) else {
+            *visibility = Visibility::Hidden;
+            continue;
+        };
+
+        node.left = Val::Px(screen.x - DAMAGE_NUMBER_WIDTH / 2.);
+        node.top = Val::Px(screen.y);
+        text_color.0 = config
+            .color(number.killing_blow)
+            .with_alpha(number.timer.fraction_remaining());
+        *visibility = Visibility::Inherited;
+    }
+}
+
+// drawn in the camera's plane so it reads as an x from wherever we're looking
+fn draw_hit_markers(
+    q_numbers: Query<&DamageNumber>,
+    q_camera: Query<&GlobalTransform, With<PrimaryCamera>>,
+    config: Res<DamageNumberConfig>,
+    mut gizmos: Gizmos,
+) {
+    let Ok(camera_transform) = q_camera.get_single() else {
+        return;
+    };
+
+    let right = camera_transform.right().as_vec3() * config.marker_size;
+    let up = camera_transform.up().as_vec3() * config.marker_size;
+
+    for number in q_numbers.iter() {
+        if number.timer.elapsed_secs() > config.marker_seconds {
+            continue;
+        }
+
+        let color = config.color(number.killing_blow);
+
+        gizmos.line(number.contact - right - up, number.contact + right + up, color);
+        gizmos.line(number.contact - right + up, number.contact + right - up, color);
+    }
+}
+
+fn despawn_damage_numbers(mut commands: Commands, q_numbers: Query<Entity, With<DamageNumber>>) {
+    for entity in q_numbers.iter() {
+        despawn(&mut commands, entity);
+    }
+}
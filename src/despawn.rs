@@ -4,7 +4,10 @@ use crate::{
         Health,
     },
     schedule::InGameSet,
-    state::GameState,
+    state::{
+        GameState,
+        PlayingGame,
+    },
 };
 use bevy::prelude::*;
 
@@ -16,7 +19,9 @@ impl Plugin for DespawnPlugin {
             Update,
             (despawn_dead_entities, despawn_missiles).in_set(InGameSet::DespawnEntities),
         )
-        .add_systems(OnEnter(GameState::GameOver), despawn_all_entities)
+        // quitting to the menu or restarting tears the run down too - not just
+        // running out of lives
+        .add_systems(OnExit(PlayingGame), despawn_all_entities)
         .add_systems(OnExit(GameState::Splash), despawn_splash);
     }
 }
@@ -43,7 +48,7 @@ fn despawn_dead_entities(mut commands: Commands, query: Query<(Entity, &Health,
 }
 
 fn despawn_all_entities(mut commands: Commands, query: Query<Entity, With<Health>>) {
-    println!("GameOver");
+    println!("run over");
     for entity in query.iter() {
         despawn(&mut commands, entity);
     }
@@ -0,0 +1,148 @@
+use crate::{
+    actor::{
+        weapon::{
+            ShotFired,
+            ShotHit,
+        },
+        ActorKind,
+        KillEvent,
+    },
+    menu::{
+        despawn_menu,
+        spawn_menu_buttons,
+        spawn_menu_root,
+        MenuEntries,
+        MenuEntry,
+        Settings,
+    },
+    schedule::InGameSet,
+    score::Score,
+    state::{
+        GameState,
+        PlayingGame,
+    },
+};
+use bevy::prelude::*;
+
+const SUMMARY_FONT_SIZE: f32 = 24.;
+
+pub struct GameOverPlugin;
+
+impl Plugin for GameOverPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<RunStats>()
+            .add_systems(OnEnter(PlayingGame), reset_run_stats)
+            .add_systems(OnEnter(GameState::GameOver), spawn_game_over_screen)
+            .add_systems(OnExit(GameState::GameOver), despawn_menu)
+            // read alongside award_points so the kills line up with the score
+            .add_systems(Update, count_kills.in_set(InGameSet::DespawnEntities))
+            .add_systems(
+                Update,
+                (count_shots, count_time_survived).in_set(InGameSet::EntityUpdates),
+            );
+    }
+}
+
+/// how the run went - time only counts while the game isn't paused
+#[derive(Resource, Debug, Default)]
+pub struct RunStats {
+    pub nateroids_destroyed: u32,
+    pub shots_fired:         u32,
+    pub shots_hit:           u32,
+    pub time_survived:       f32,
+}
+
+impl RunStats {
+    /// percentage of shots fired that landed on something
+    pub fn accuracy(&self) -> f32 {
+        if self.shots_fired == 0 {
+            return 0.0;
+        }
+
+        self.shots_hit as f32 / self.shots_fired as f32 * 100.
+    }
+}
+
+fn reset_run_stats(mut run_stats: ResMut<RunStats>) { *run_stats = RunStats::default(); }
+
+fn count_kills(mut kill_events: EventReader<KillEvent>, mut run_stats: ResMut<RunStats>) {
+    for kill in kill_events.read() {
+        if kill.victim_kind == ActorKind::Nateroid && kill.killer_player.is_some() {
+            run_stats.nateroids_destroyed += 1;
+        }
+    }
+}
+
+fn count_shots(
+    mut shots_fired: EventReader<ShotFired>,
+    mut shots_hit: EventReader<ShotHit>,
+    mut run_stats: ResMut<RunStats>,
+) {
+    run_stats.shots_fired += shots_fired.read().count() as u32;
+    run_stats.shots_hit += shots_hit.read().count() as u32;
+}
+
+fn count_time_survived(mut run_stats: ResMut<RunStats>, time: Res<Time>) {
+    run_stats.time_survived += time.delta_secs();
+}
+
+// nothing happens until the player picks one of the choices - restart goes
+// straight back into a fresh game
+fn spawn_game_over_screen(
+    mut commands: Commands,
+    run_stats: Res<RunStats>,
+    score: Res<Score>,
+    settings: Settings,
+) {
+    let entries = MenuEntries::new(vec![MenuEntry::Restart, MenuEntry::MainMenu]);
+    let root = spawn_menu_root(&mut commands, "game over");
+
+    let minutes = (run_stats.time_survived / 60.).floor();
+    let seconds = run_stats.time_survived % 60.;
+
+    let summary = [
+        format!("final score: {}", score.total),
+        format!("time survived: {:.0}:{:02.0}", minutes, seconds.floor()),
+        format!("nateroids destroyed: {}", run_stats.nateroids_destroyed),
+        format!("shots fired: {}", run_stats.shots_fired),
+        format!("accuracy: {:.1}%", run_stats.accuracy()),
+    ];
+
+    commands.entity(root).with_children(|root| {
+        root.spawn((
+            Text::new(summary.join("\n")),
+            TextFont {
+                font_size: SUMMARY_FONT_SIZE,
+                ..default()
+            },
+            TextLayout::new_with_justify(JustifyText::Center),
+        ));
+    });
+
+    spawn_menu_buttons(&mut commands, root, &entries, &settings);
+    commands.insert_resource(entries);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run_stats(shots_fired: u32, shots_hit: u32) -> RunStats {
+        RunStats {
+            shots_fired,
+            shots_hit,
+            ..default()
+        }
+    }
+
+    #[test]
+    fn accuracy_is_the_percentage_of_shots_that_hit() {
+        assert_eq!(run_stats(4, 1).accuracy(), 25.);
+        assert_eq!(run_stats(4, 4).accuracy(), 100.);
+    }
+
+    #[test]
+    fn accuracy_without_shots_is_zero() {
+        assert_eq!(run_stats(0, 0).accuracy(), 0.);
+    }
+}
@@ -19,20 +19,34 @@ impl Plugin for InputPlugin {
 #[derive(Actionlike, EnumIter, Reflect, PartialEq, Eq, Clone, Copy, Hash, Debug)]
 pub enum GlobalAction {
     AABBs,
+    BossInspector,
     BoundaryInspector,
     CameraConfigInspector,
+    CycleOrientation,
+    DamageNumbers,
+    DamageNumbersInspector,
     Debug,
+    HyperspaceInspector,
     LightsInspector,
     MissileInspector,
     NateroidInspector,
     PhysicsAABB,
+    PlanetInspector,
     PlanesInspector,
+    PlayerInspector,
     PortalInspector,
     Pause,
+    RadarInspector,
+    SaucerInspector,
+    ScoreInspector,
+    ShieldInspector,
     SpaceshipInspector,
     SpaceshipControlInspector,
     Stars,
     SuppressNateroids,
+    VersusInspector,
+    WaveInspector,
+    WeaponInspector,
 }
 
 /// GlobalActions assign keys to do a lot of obvious stuff. Debug is less
@@ -74,20 +88,34 @@ impl GlobalAction {
         // accumulation works
         Self::iter().fold(InputMap::default(), |input_map, action| match action {
             Self::AABBs => input_map.with(action, KeyCode::F1),
+            Self::BossInspector => insert_shift_input(input_map, action, KeyCode::KeyN),
             Self::BoundaryInspector => insert_shift_input(input_map, action, KeyCode::KeyB),
             Self::CameraConfigInspector => insert_shift_input(input_map, action, KeyCode::KeyC),
+            Self::CycleOrientation => input_map.with(action, KeyCode::F6),
+            Self::DamageNumbers => input_map.with(action, KeyCode::F7),
+            Self::DamageNumbersInspector => insert_shift_input(input_map, action, KeyCode::KeyH),
             Self::Debug => insert_shift_input(input_map, action, KeyCode::KeyD),
+            Self::HyperspaceInspector => insert_shift_input(input_map, action, KeyCode::KeyY),
             Self::LightsInspector => insert_shift_input(input_map, action, KeyCode::KeyL),
             Self::MissileInspector => insert_shift_input(input_map, action, KeyCode::Digit1),
             Self::NateroidInspector => insert_shift_input(input_map, action, KeyCode::Digit2),
             Self::Pause => input_map.with(action, KeyCode::Escape),
             Self::PhysicsAABB => input_map.with(action, KeyCode::F2),
+            Self::PlanetInspector => insert_shift_input(input_map, action, KeyCode::Digit0),
             Self::PlanesInspector => insert_shift_input(input_map, action, KeyCode::KeyP),
+            Self::PlayerInspector => insert_shift_input(input_map, action, KeyCode::KeyM),
             Self::PortalInspector => insert_shift_input(input_map, action, KeyCode::KeyG),
+            Self::RadarInspector => insert_shift_input(input_map, action, KeyCode::KeyR),
+            Self::SaucerInspector => insert_shift_input(input_map, action, KeyCode::Digit7),
+            Self::ScoreInspector => insert_shift_input(input_map, action, KeyCode::Digit5),
+            Self::ShieldInspector => insert_shift_input(input_map, action, KeyCode::Digit8),
             Self::SpaceshipInspector => insert_shift_input(input_map, action, KeyCode::Digit3),
             Self::SpaceshipControlInspector => insert_shift_input(input_map, action, KeyCode::Digit4),
             Self::Stars => input_map.with(action, KeyCode::F3),
             Self::SuppressNateroids => input_map.with(action, KeyCode::F4),
+            Self::VersusInspector => insert_shift_input(input_map, action, KeyCode::KeyV),
+            Self::WaveInspector => insert_shift_input(input_map, action, KeyCode::Digit6),
+            Self::WeaponInspector => insert_shift_input(input_map, action, KeyCode::Digit9),
         })
     }
 }
@@ -0,0 +1,269 @@
+use crate::{
+    actor::{
+        player::{
+            Player,
+            PlayerConfig,
+            MAX_PLAYERS,
+        },
+        weapon::Weapon,
+        ContinuousFire,
+        Health,
+        Lives,
+        Spaceship,
+        SpaceshipConfig,
+        Wave,
+    },
+    camera::RenderLayer,
+    score::Score,
+    state::PlayingGame,
+};
+use bevy::{
+    color::palettes::tailwind,
+    prelude::*,
+    render::view::RenderLayers,
+};
+
+const HUD_FONT_SIZE: f32 = 18.;
+const HEALTH_BAR_WIDTH: f32 = 160.;
+const HEALTH_BAR_HEIGHT: f32 = 8.;
+
+pub struct HudPlugin;
+
+impl Plugin for HudPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Startup, spawn_hud)
+            .add_systems(OnEnter(PlayingGame), show_hud)
+            .add_systems(OnExit(PlayingGame), hide_hud)
+            .add_systems(
+                Update,
+                (
+                    update_wave_text,
+                    update_score_text,
+                    update_player_panels,
+                    update_health_bars,
+                    update_weapon_text,
+                )
+                    .run_if(in_state(PlayingGame)),
+            );
+    }
+}
+
+#[derive(Component)]
+struct Hud;
+
+#[derive(Component)]
+struct WaveText;
+
+#[derive(Component)]
+struct ScoreText;
+
+#[derive(Component)]
+struct PlayerPanel(Player);
+
+// score and lives for one player
+#[derive(Component)]
+struct PlayerText(Player);
+
+// the fill of the bar - its width is the fraction of health left
+#[derive(Component)]
+struct HealthBar(Player);
+
+#[derive(Component)]
+struct WeaponText(Player);
+
+fn hud_text(value: impl Into<String>) -> (Text, TextFont) {
+    (
+        Text::new(value),
+        TextFont {
+            font_size: HUD_FONT_SIZE,
+            ..default()
+        },
+    )
+}
+
+// everything is spawned up front - panels for players that aren't in the game
+// are just hidden so changing player_count between games doesn't mean
+// rebuilding the hud
+fn spawn_hud(mut commands: Commands) {
+    commands
+        .spawn((
+            Hud,
+            Node {
+                width: Val::Percent(100.),
+                position_type: PositionType::Absolute,
+                justify_content: JustifyContent::SpaceBetween,
+                padding: UiRect::all(Val::Px(10.)),
+                ..default()
+            },
+            Visibility::Hidden,
+            RenderLayers::from_layers(RenderLayer::Game.layers()),
+        ))
+        .with_children(|hud| {
+            hud.spawn(Node {
+                flex_direction: FlexDirection::Column,
+                ..default()
+            })
+            .with_children(|summary| {
+                summary.spawn((WaveText, hud_text("wave 0")));
+                summary.spawn((ScoreText, hud_text("score 0")));
+            });
+
+            for index in 0..MAX_PLAYERS {
+                let player = Player(index);
+
+                hud.spawn((
+                    PlayerPanel(player),
+                    Node {
+                        flex_direction: FlexDirection::Column,
+                        row_gap: Val::Px(4.),
+                        ..default()
+                    },
+                ))
+                .with_children(|panel| {
+                    panel.spawn((PlayerText(player), hud_text("")));
+                    panel
+                        .spawn((
+                            Node {
+                                width: Val::Px(HEALTH_BAR_WIDTH),
+                                height: Val::Px(HEALTH_BAR_HEIGHT),
+                                ..default()
+                            },
+                            BackgroundColor(Color::from(tailwind::SLATE_700)),
+                        ))
+                        .with_children(|bar| {
+                            bar.spawn((
+                                HealthBar(player),
+                                Node {
+                                    width: Val::Percent(100.),
+                                    height: Val::Percent(100.),
+                                    ..default()
+                                },
+                                BackgroundColor(Color::from(tailwind::GREEN_500)),
+                            ));
+                        });
+                    panel.spawn((WeaponText(player), hud_text("")));
+                });
+            }
+        });
+}
+
+fn show_hud(mut q_hud: Query<&mut Visibility, With<Hud>>) {
+    for mut visibility in q_hud.iter_mut() {
+        *visibility = Visibility::Inherited;
+    }
+}
+
+fn hide_hud(mut q_hud: Query<&mut Visibility, With<Hud>>) {
+    for mut visibility in q_hud.iter_mut() {
+        *visibility = Visibility::Hidden;
+    }
+}
+
+fn update_wave_text(wave: Res<Wave>, mut q_text: Query<&mut Text, With<WaveText>>) {
+    if !wave.is_changed() {
+        return;
+    }
+
+    for mut text in q_text.iter_mut() {
+        **text = format!("wave {}", wave.number);
+    }
+}
+
+fn update_score_text(score: Res<Score>, mut q_text: Query<&mut Text, With<ScoreText>>) {
+    if !score.is_changed() {
+        return;
+    }
+
+    for mut text in q_text.iter_mut() {
+        **text = format!("score {}", score.total);
+    }
+}
+
+// with only one player their score is the total so there's no need to repeat it
+fn update_player_panels(
+    mut q_panels: Query<(&PlayerPanel, &mut Visibility)>,
+    mut q_text: Query<(&PlayerText, &mut Text, &mut TextColor)>,
+    lives: Res<Lives>,
+    player_config: Res<PlayerConfig>,
+    score: Res<Score>,
+) {
+    if !lives.is_changed() && !player_config.is_changed() && !score.is_changed() {
+        return;
+    }
+
+    let player_count = player_config.player_count();
+
+    for (panel, mut visibility) in q_panels.iter_mut() {
+        *visibility = if panel.0 .0 < player_count {
+            Visibility::Inherited
+        } else {
+            Visibility::Hidden
+        };
+    }
+
+    for (player_text, mut text, mut color) in q_text.iter_mut() {
+        let player = player_text.0;
+        let remaining = lives.0.get(player.0).map_or(0, |lives| lives.remaining);
+
+        **text = if player_count > 1 {
+            let points = score.player_totals.get(player.0).copied().unwrap_or(0);
+            format!("player {}  {} points  lives {}", player.0 + 1, points, remaining)
+        } else {
+            format!("lives {}", remaining)
+        };
+        color.0 = player_config.color(player);
+    }
+}
+
+// a player waiting to respawn has no spaceship so their bar reads empty
+fn update_health_bars(
+    q_spaceships: Query<(&Player, &Health), With<Spaceship>>,
+    mut q_bars: Query<(&HealthBar, &mut Node)>,
+    spaceship_config: Res<SpaceshipConfig>,
+) {
+    let max_health = spaceship_config.0.health.max(f32::EPSILON);
+
+    for (bar, mut node) in q_bars.iter_mut() {
+        let health = q_spaceships
+            .iter()
+            .find(|(player, _)| **player == bar.0)
+            .map_or(0.0, |(_, health)| health.0);
+
+        node.width = Val::Percent((health / max_health).clamp(0.0, 1.0) * 100.);
+    }
+}
+
+fn update_weapon_text(
+    q_spaceships: Query<(&Player, &Weapon, Option<&ContinuousFire>), With<Spaceship>>,
+    mut q_text: Query<(&WeaponText, &mut Text)>,
+) {
+    for (weapon_text, mut text) in q_text.iter_mut() {
+        let Some((_, weapon, continuous_fire)) =
+            q_spaceships.iter().find(|(player, _, _)| **player == weapon_text.0)
+        else {
+            if !text.is_empty() {
+                text.clear();
+            }
+            continue;
+        };
+
+        let gauge = weapon.gauge(weapon.kind);
+        let mut status = format!("{:?}", weapon.kind).to_lowercase();
+
+        if gauge.overheated {
+            status.push_str("  overheated");
+        } else if gauge.heat > 0.0 {
+            status.push_str(&format!("  heat {:.0}%", gauge.heat * 100.));
+        }
+        if let Some(ammo) = gauge.ammo {
+            status.push_str(&format!("  ammo {}", ammo));
+        }
+        if continuous_fire.is_some() {
+            status.push_str("  continuous");
+        }
+
+        if **text != status {
+            **text = status;
+        }
+    }
+}
@@ -1,26 +1,40 @@
 // exclude when targeting wasm - this breaks in the browser right now
 mod actor;
 mod asset_loader;
+mod bindings;
 mod camera;
+mod damage_numbers;
 mod despawn;
+mod game_over;
 mod global_input;
+mod hud;
+mod menu;
 mod orientation;
 mod physics;
 mod playfield;
+mod radar;
 mod schedule;
+mod score;
 mod splash;
 mod state;
 
 use crate::{
     actor::ActorPlugin,
     asset_loader::AssetLoaderPlugin,
+    bindings::BindingsPlugin,
     camera::CameraPlugin,
+    damage_numbers::DamageNumbersPlugin,
     despawn::DespawnPlugin,
+    game_over::GameOverPlugin,
     global_input::InputPlugin,
+    hud::HudPlugin,
+    menu::MenuPlugin,
     orientation::OrientationPlugin,
     physics::PhysicsPlugin,
     playfield::PlayfieldPlugin,
+    radar::RadarPlugin,
     schedule::SchedulePlugin,
+    score::ScorePlugin,
     splash::SplashPlugin,
     state::StatePlugin,
 };
@@ -60,12 +74,17 @@ fn main() {
         PlayfieldPlugin,
         CameraPlugin,
         DespawnPlugin,
+        GameOverPlugin,
+        HudPlugin,
         InputPlugin,
+        MenuPlugin,
         OrientationPlugin,
         PhysicsPlugin,
         SchedulePlugin,
+        ScorePlugin,
         SplashPlugin,
         StatePlugin,
     ))
+    .add_plugins((BindingsPlugin, DamageNumbersPlugin, RadarPlugin))
     .run();
 }
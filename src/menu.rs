@@ -0,0 +1,683 @@
+use crate::{
+    actor::{
+        ControlScheme,
+        FlightModel,
+        SpaceshipControl,
+        SpaceshipControlConfig,
+    },
+    bindings::{
+        capture_binding,
+        BindingPage,
+        BindingTarget,
+        InputBindings,
+        Rebinding,
+    },
+    camera::{
+        CameraConfig,
+        LightConfig,
+        RenderLayer,
+        StarConfig,
+    },
+    despawn::despawn,
+    state::{
+        GameState,
+        IsPaused,
+        MenuScreen,
+        PauseScreen,
+    },
+};
+use bevy::{
+    app::AppExit,
+    color::palettes::tailwind,
+    ecs::system::SystemParam,
+    prelude::*,
+    render::view::RenderLayers,
+};
+use leafwing_input_manager::prelude::*;
+use strum::{
+    EnumIter,
+    IntoEnumIterator,
+};
+
+const MENU_FONT_SIZE: f32 = 28.;
+const TITLE_FONT_SIZE: f32 = 64.;
+const CONTROLS_FONT_SIZE: f32 = 16.;
+const BINDING_FONT_SIZE: f32 = 20.;
+
+pub struct MenuPlugin;
+
+impl Plugin for MenuPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugins(InputManagerPlugin::<MenuAction>::default())
+            .init_resource::<ActionState<MenuAction>>()
+            .insert_resource(MenuAction::menu_input_map())
+            .init_resource::<MenuEntries>()
+            .add_systems(OnEnter(MenuScreen::Bindings), spawn_bindings_menu)
+            .add_systems(OnEnter(MenuScreen::Controls), spawn_controls_menu)
+            .add_systems(OnEnter(MenuScreen::Main), spawn_main_menu)
+            .add_systems(OnEnter(MenuScreen::Settings), spawn_settings_menu)
+            .add_systems(OnExit(MenuScreen::Bindings), despawn_menu)
+            .add_systems(OnExit(MenuScreen::Controls), despawn_menu)
+            .add_systems(OnExit(MenuScreen::Main), despawn_menu)
+            .add_systems(OnExit(MenuScreen::Settings), despawn_menu)
+            .add_systems(OnEnter(PauseScreen::Main), spawn_pause_menu)
+            .add_systems(OnEnter(PauseScreen::Settings), spawn_settings_menu)
+            .add_systems(OnExit(PauseScreen::Main), despawn_menu)
+            .add_systems(OnExit(PauseScreen::Settings), despawn_menu)
+            .add_systems(
+                Update,
+                refresh_bindings_menu
+                    .run_if(in_state(MenuScreen::Bindings).and(resource_changed::<BindingPage>)),
+            )
+            .add_systems(
+                Update,
+                (navigate_menu, click_menu, capture_binding, update_menu_labels)
+                    .chain()
+                    .run_if(
+                        in_state(GameState::MainMenu)
+                            .or(in_state(GameState::GameOver))
+                            .or(in_state(IsPaused::Paused)),
+                    ),
+            );
+    }
+}
+
+// the menus can be driven from the keyboard or a gamepad as well as the mouse
+#[derive(Actionlike, EnumIter, Reflect, PartialEq, Eq, Clone, Copy, Hash, Debug)]
+pub enum MenuAction {
+    Back,
+    Decrease,
+    Down,
+    Increase,
+    Select,
+    Up,
+}
+
+impl MenuAction {
+    pub fn menu_input_map() -> InputMap<Self> {
+        Self::iter().fold(InputMap::default(), |input_map, action| match action {
+            Self::Back => input_map
+                .with(action, KeyCode::Escape)
+                .with(action, KeyCode::Backspace)
+                .with(action, GamepadButton::East),
+            Self::Decrease => input_map
+                .with(action, KeyCode::ArrowLeft)
+                .with(action, KeyCode::KeyA)
+                .with(action, GamepadButton::DPadLeft),
+            Self::Down => input_map
+                .with(action, KeyCode::ArrowDown)
+                .with(action, KeyCode::KeyS)
+                .with(action, GamepadButton::DPadDown),
+            Self::Increase => input_map
+                .with(action, KeyCode::ArrowRight)
+                .with(action, KeyCode::KeyD)
+                .with(action, GamepadButton::DPadRight),
+            Self::Select => input_map
+                .with(action, KeyCode::Enter)
+                .with(action, KeyCode::Space)
+                .with(action, GamepadButton::South),
+            Self::Up => input_map
+                .with(action, KeyCode::ArrowUp)
+                .with(action, KeyCode::KeyW)
+                .with(action, GamepadButton::DPadUp),
+        })
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum MenuEntry {
+    Back,
+    BindingPage,
+    Bindings,
+    Controls,
+    MainMenu,
+    Quit,
+    Rebind(BindingTarget),
+    ResetBindings,
+    Restart,
+    Resume,
+    Setting(Setting),
+    Settings,
+    Start,
+}
+
+impl MenuEntry {
+    fn label(self, settings: &Settings) -> String {
+        match self {
+            Self::Back => "back".to_string(),
+            Self::BindingPage => format!("showing   < {} >", settings.binding_page.label()),
+            Self::Bindings => "rebind keys".to_string(),
+            Self::Controls => "controls".to_string(),
+            Self::MainMenu => "main menu".to_string(),
+            Self::Quit => "quit".to_string(),
+            Self::Rebind(target) => settings.rebinding.label(target, &settings.bindings),
+            Self::ResetBindings => "reset to defaults".to_string(),
+            Self::Restart => "restart".to_string(),
+            Self::Resume => "resume".to_string(),
+            Self::Setting(setting) => format!("{}   < {} >", setting.label(), setting.value(settings)),
+            Self::Settings => "settings".to_string(),
+            Self::Start => "start".to_string(),
+        }
+    }
+
+    // there are a lot of actions to rebind so they're smaller to fit
+    fn font_size(self) -> f32 {
+        match self {
+            Self::Rebind(_) => BINDING_FONT_SIZE,
+            _ => MENU_FONT_SIZE,
+        }
+    }
+}
+
+/// the entries on the screen that's showing - selected is what the keyboard
+/// acts on and follows the mouse around
+#[derive(Resource, Debug, Default)]
+pub(crate) struct MenuEntries {
+    entries:  Vec<MenuEntry>,
+    selected: usize,
+}
+
+impl MenuEntries {
+    pub(crate) fn new(entries: Vec<MenuEntry>) -> Self { Self { entries, selected: 0 } }
+
+    fn selected(&self) -> Option<MenuEntry> { self.entries.get(self.selected).copied() }
+}
+
+#[derive(Component)]
+pub(crate) struct MenuRoot;
+
+#[derive(Component)]
+struct MenuButton(MenuEntry);
+
+// a curated handful of the inspector settings - the ones a player might
+// actually want to change
+#[derive(EnumIter, Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum Setting {
+    AmbientLight,
+    Bloom,
+    ChaseCamera,
+    FlightModel,
+    MouseZoom,
+    TopSpeed,
+    TurnSpeed,
+    Twinkling,
+}
+
+#[derive(SystemParam)]
+pub(crate) struct Settings<'w> {
+    binding_page:      ResMut<'w, BindingPage>,
+    bindings:          ResMut<'w, InputBindings>,
+    camera:            ResMut<'w, CameraConfig>,
+    lights:            ResMut<'w, LightConfig>,
+    rebinding:         ResMut<'w, Rebinding>,
+    spaceship_control: ResMut<'w, SpaceshipControlConfig>,
+    stars:             ResMut<'w, StarConfig>,
+}
+
+impl Setting {
+    fn label(self) -> &'static str {
+        match self {
+            Self::AmbientLight => "ambient light",
+            Self::Bloom => "star glow",
+            Self::ChaseCamera => "chase camera stiffness",
+            Self::FlightModel => "flight model",
+            Self::MouseZoom => "mouse zoom speed",
+            Self::TopSpeed => "top speed",
+            Self::TurnSpeed => "turn speed",
+            Self::Twinkling => "twinkling stars",
+        }
+    }
+
+    fn value(self, settings: &Settings) -> String {
+        match self {
+            Self::AmbientLight => format!("{:.0}", settings.lights.ambient_light_brightness),
+            Self::Bloom => format!("{:.0}%", settings.camera.bloom_intensity * 100.),
+            Self::ChaseCamera => format!("{:.0}", settings.camera.chase_stiffness),
+            Self::FlightModel => format!("{:?}", settings.spaceship_control.flight_model).to_lowercase(),
+            Self::MouseZoom => format!("{:.0}", settings.camera.zoom_sensitivity_mouse),
+            Self::TopSpeed => format!("{:.0}", settings.spaceship_control.max_speed),
+            Self::TurnSpeed => format!("{:.1}", settings.spaceship_control.rotation_speed),
+            Self::Twinkling => settings.stars.twinkle_choose_multiple_count.to_string(),
+        }
+    }
+
+    // the keyboard stops at either end - clicking wraps back around since
+    // there's only one button to click
+    fn adjust(self, settings: &mut Settings, direction: f32, wrap: bool) {
+        let step = |value: f32, min: f32, max: f32, step: f32| {
+            let stepped = value + step * direction;
+            match (wrap, stepped) {
+                (true, stepped) if stepped > max + step / 2.0 => min,
+                (true, stepped) if stepped < min - step / 2.0 => max,
+                _ => stepped.clamp(min, max),
+            }
+        };
+
+        match self {
+            Self::AmbientLight => {
+                let lights = &mut settings.lights;
+                lights.ambient_light_brightness = step(lights.ambient_light_brightness, 0., 1_000., 50.);
+            },
+            Self::Bloom => {
+                settings.camera.bloom_intensity = step(settings.camera.bloom_intensity, 0., 1., 0.05);
+            },
+            Self::ChaseCamera => {
+                settings.camera.chase_stiffness = step(settings.camera.chase_stiffness, 10., 200., 10.);
+            },
+            Self::FlightModel => {
+                settings.spaceship_control.flight_model = match settings.spaceship_control.flight_model {
+                    FlightModel::Arcade => FlightModel::Newtonian,
+                    FlightModel::Newtonian => FlightModel::Arcade,
+                };
+            },
+            Self::MouseZoom => {
+                let camera = &mut settings.camera;
+                camera.zoom_sensitivity_mouse = step(camera.zoom_sensitivity_mouse, 1., 20., 1.);
+            },
+            Self::TopSpeed => {
+                let control = &mut settings.spaceship_control;
+                control.max_speed = step(control.max_speed, 50., 300., 10.);
+            },
+            Self::TurnSpeed => {
+                let control = &mut settings.spaceship_control;
+                control.rotation_speed = step(control.rotation_speed, 1., 10., 0.5);
+            },
+            Self::Twinkling => {
+                let stars = &mut settings.stars;
+                let count = step(stars.twinkle_choose_multiple_count as f32, 0., 20., 1.);
+                stars.twinkle_choose_multiple_count = count.round() as usize;
+            },
+        }
+    }
+}
+
+// the backdrop dims whatever is behind the menu - the paused game for one -
+// rather than hiding it
+pub(crate) fn spawn_menu_root(commands: &mut Commands, title: &str) -> Entity {
+    commands
+        .spawn((
+            MenuRoot,
+            Node {
+                width: Val::Percent(100.),
+                height: Val::Percent(100.),
+                position_type: PositionType::Absolute,
+                flex_direction: FlexDirection::Column,
+                align_items: AlignItems::Center,
+                justify_content: JustifyContent::Center,
+                row_gap: Val::Px(12.),
+                ..default()
+            },
+            BackgroundColor(Color::BLACK.with_alpha(0.6)),
+            RenderLayers::from_layers(RenderLayer::Game.layers()),
+        ))
+        .with_children(|root| {
+            root.spawn((
+                Text::new(title),
+                TextFont {
+                    font_size: TITLE_FONT_SIZE,
+                    ..default()
+                },
+            ));
+        })
+        .id()
+}
+
+pub(crate) fn spawn_menu_buttons(
+    commands: &mut Commands,
+    root: Entity,
+    entries: &MenuEntries,
+    settings: &Settings,
+) {
+    commands.entity(root).with_children(|root| {
+        for &entry in entries.entries.iter() {
+            spawn_menu_button(root, entry, settings);
+        }
+    });
+}
+
+fn spawn_menu_button(parent: &mut ChildBuilder, entry: MenuEntry, settings: &Settings) {
+    parent
+        .spawn((
+            MenuButton(entry),
+            Button,
+            Node {
+                padding: UiRect::axes(Val::Px(16.), Val::Px(4.)),
+                ..default()
+            },
+            BackgroundColor(Color::NONE),
+        ))
+        .with_children(|button| {
+            button.spawn((
+                Text::new(entry.label(settings)),
+                TextFont {
+                    font_size: entry.font_size(),
+                    ..default()
+                },
+            ));
+        });
+}
+
+fn spawn_main_menu(mut commands: Commands, settings: Settings) {
+    let entries = MenuEntries::new(vec![
+        MenuEntry::Start,
+        MenuEntry::Settings,
+        MenuEntry::Controls,
+        MenuEntry::Quit,
+    ]);
+
+    let root = spawn_menu_root(&mut commands, "nateroids");
+    spawn_menu_buttons(&mut commands, root, &entries, &settings);
+    commands.insert_resource(entries);
+}
+
+fn spawn_pause_menu(mut commands: Commands, settings: Settings) {
+    let entries = MenuEntries::new(vec![
+        MenuEntry::Resume,
+        MenuEntry::Restart,
+        MenuEntry::Settings,
+        MenuEntry::MainMenu,
+    ]);
+
+    let root = spawn_menu_root(&mut commands, "paused");
+    spawn_menu_buttons(&mut commands, root, &entries, &settings);
+    commands.insert_resource(entries);
+}
+
+fn spawn_settings_menu(mut commands: Commands, settings: Settings) {
+    let entries = MenuEntries::new(
+        Setting::iter()
+            .map(MenuEntry::Setting)
+            .chain(std::iter::once(MenuEntry::Back))
+            .collect(),
+    );
+
+    let root = spawn_menu_root(&mut commands, "settings");
+    spawn_menu_buttons(&mut commands, root, &entries, &settings);
+    commands.insert_resource(entries);
+}
+
+// the keyboard players' bindings are read straight out of their input maps so
+// this can't drift from what the keys actually do - rebinding included
+fn spawn_controls_menu(mut commands: Commands, settings: Settings) {
+    let entries = MenuEntries::new(vec![MenuEntry::Bindings, MenuEntry::Back]);
+    let root = spawn_menu_root(&mut commands, "controls");
+
+    let schemes = [
+        ControlScheme::SinglePlayer,
+        ControlScheme::LeftKeyboard,
+        ControlScheme::RightKeyboard,
+    ];
+
+    commands.entity(root).with_children(|root| {
+        root.spawn(Node {
+            column_gap: Val::Px(32.),
+            ..default()
+        })
+        .with_children(|columns| {
+            for scheme in schemes {
+                let mut lines = vec![scheme.label().to_string()];
+                lines.extend(SpaceshipControl::iter().map(|action| {
+                    let target = BindingTarget::Spaceship(scheme, action);
+                    format!("{:?}: {}", action, settings.bindings.describe(target))
+                }));
+
+                columns.spawn((
+                    Text::new(lines.join("\n")),
+                    TextFont {
+                        font_size: CONTROLS_FONT_SIZE,
+                        ..default()
+                    },
+                ));
+            }
+        });
+
+        root.spawn((
+            Text::new("players 3 and 4 use gamepads - escape pauses the game"),
+            TextFont {
+                font_size: CONTROLS_FONT_SIZE,
+                ..default()
+            },
+        ));
+    });
+
+    spawn_menu_buttons(&mut commands, root, &entries, &settings);
+    commands.insert_resource(entries);
+}
+
+// one page of actions at a time - they wrap into columns when there are more
+// than fit down the screen
+fn spawn_bindings_menu(mut commands: Commands, settings: Settings) {
+    build_bindings_menu(&mut commands, &settings);
+}
+
+fn build_bindings_menu(commands: &mut Commands, settings: &Settings) {
+    let rebinds: Vec<MenuEntry> = settings
+        .binding_page
+        .targets()
+        .into_iter()
+        .map(MenuEntry::Rebind)
+        .collect();
+
+    let entries = MenuEntries::new(
+        std::iter::once(MenuEntry::BindingPage)
+            .chain(rebinds.iter().copied())
+            .chain([MenuEntry::ResetBindings, MenuEntry::Back])
+            .collect(),
+    );
+
+    let root = spawn_menu_root(commands, "rebind keys");
+
+    commands.entity(root).with_children(|root| {
+        spawn_menu_button(root, MenuEntry::BindingPage, settings);
+
+        root.spawn(Node {
+            height: Val::Percent(60.),
+            flex_direction: FlexDirection::Column,
+            flex_wrap: FlexWrap::Wrap,
+            align_content: AlignContent::Center,
+            column_gap: Val::Px(32.),
+            ..default()
+        })
+        .with_children(|columns| {
+            for &entry in rebinds.iter() {
+                spawn_menu_button(columns, entry, settings);
+            }
+        });
+
+        spawn_menu_button(root, MenuEntry::ResetBindings, settings);
+        spawn_menu_button(root, MenuEntry::Back, settings);
+    });
+
+    commands.insert_resource(entries);
+}
+
+// switching pages swaps out the whole list of actions
+fn refresh_bindings_menu(mut commands: Commands, q_menu: Query<Entity, With<MenuRoot>>, settings: Settings) {
+    for entity in q_menu.iter() {
+        despawn(&mut commands, entity);
+    }
+
+    build_bindings_menu(&mut commands, &settings);
+}
+
+pub(crate) fn despawn_menu(mut commands: Commands, q_menu: Query<Entity, With<MenuRoot>>) {
+    for entity in q_menu.iter() {
+        despawn(&mut commands, entity);
+    }
+}
+
+// where each menu choice takes us - back returns to the first screen of
+// whichever menu is open
+#[derive(SystemParam)]
+struct MenuNavigation<'w> {
+    app_exit:          EventWriter<'w, AppExit>,
+    game_state:        Res<'w, State<GameState>>,
+    menu_screen:       Option<Res<'w, State<MenuScreen>>>,
+    next_game_state:   ResMut<'w, NextState<GameState>>,
+    next_menu_screen:  ResMut<'w, NextState<MenuScreen>>,
+    next_pause_screen: ResMut<'w, NextState<PauseScreen>>,
+    pause_screen:      Option<Res<'w, State<PauseScreen>>>,
+}
+
+impl MenuNavigation<'_> {
+    // the game over screen has nowhere to go back to and escape on the first
+    // pause screen is left to toggle_pause
+    fn back(&mut self) {
+        match self.menu_screen.as_ref().map(|screen| *screen.get()) {
+            Some(MenuScreen::Bindings) => self.next_menu_screen.set(MenuScreen::Controls),
+            Some(MenuScreen::Controls | MenuScreen::Settings) => self.next_menu_screen.set(MenuScreen::Main),
+            Some(MenuScreen::Main) | None => {},
+        }
+        if self.pause_screen.as_ref().is_some_and(|screen| *screen.get() != PauseScreen::Main) {
+            self.next_pause_screen.set(PauseScreen::Main);
+        }
+    }
+
+    // resuming keeps whatever the inspector was up to
+    fn play(&mut self) {
+        let inspecting = match self.game_state.get() {
+            GameState::InGame { inspecting, .. } => *inspecting,
+            _ => false,
+        };
+
+        self.next_game_state.set(GameState::InGame {
+            paused: false,
+            inspecting,
+        });
+    }
+
+    fn activate(&mut self, entry: MenuEntry, settings: &mut Settings) {
+        match entry {
+            MenuEntry::Back => self.back(),
+            MenuEntry::BindingPage => settings.binding_page.cycle(1),
+            MenuEntry::Bindings => self.next_menu_screen.set(MenuScreen::Bindings),
+            MenuEntry::Controls => self.next_menu_screen.set(MenuScreen::Controls),
+            MenuEntry::MainMenu => self.next_game_state.set(GameState::MainMenu),
+            MenuEntry::Quit => {
+                self.app_exit.send(AppExit::Success);
+            },
+            MenuEntry::Rebind(target) => settings.rebinding.capture(target),
+            MenuEntry::ResetBindings => {
+                let page = *settings.binding_page;
+                settings.bindings.reset(page);
+                settings.bindings.save();
+            },
+            MenuEntry::Restart => self.next_game_state.set(GameState::Restarting),
+            MenuEntry::Resume | MenuEntry::Start => self.play(),
+            MenuEntry::Setting(setting) => setting.adjust(settings, 1.0, true),
+            MenuEntry::Settings => {
+                if self.pause_screen.is_some() {
+                    self.next_pause_screen.set(PauseScreen::Settings);
+                } else {
+                    self.next_menu_screen.set(MenuScreen::Settings);
+                }
+            },
+        }
+    }
+}
+
+fn navigate_menu(
+    menu_input: Res<ActionState<MenuAction>>,
+    mut entries: ResMut<MenuEntries>,
+    mut settings: Settings,
+    mut navigation: MenuNavigation,
+) {
+    // capture_binding has the keyboard while a rebind is waiting on a key
+    if settings.rebinding.is_capturing() {
+        return;
+    }
+
+    let entry_count = entries.entries.len().max(1);
+
+    if menu_input.just_pressed(&MenuAction::Up) {
+        entries.selected = (entries.selected + entry_count - 1) % entry_count;
+    }
+    if menu_input.just_pressed(&MenuAction::Down) {
+        entries.selected = (entries.selected + 1) % entry_count;
+    }
+
+    let Some(selected) = entries.selected() else {
+        return;
+    };
+
+    if let MenuEntry::Setting(setting) = selected {
+        if menu_input.just_pressed(&MenuAction::Decrease) {
+            setting.adjust(&mut settings, -1.0, false);
+        }
+        if menu_input.just_pressed(&MenuAction::Increase) {
+            setting.adjust(&mut settings, 1.0, false);
+        }
+    }
+
+    if selected == MenuEntry::BindingPage {
+        if menu_input.just_pressed(&MenuAction::Decrease) {
+            settings.binding_page.cycle(-1);
+        }
+        if menu_input.just_pressed(&MenuAction::Increase) {
+            settings.binding_page.cycle(1);
+        }
+    }
+
+    if menu_input.just_pressed(&MenuAction::Select) {
+        navigation.activate(selected, &mut settings);
+    } else if menu_input.just_pressed(&MenuAction::Back) {
+        navigation.back();
+    }
+}
+
+// hovering selects - which keeps the keyboard and mouse from fighting over
+// which entry is highlighted
+fn click_menu(
+    q_buttons: Query<(&Interaction, &MenuButton), Changed<Interaction>>,
+    mut entries: ResMut<MenuEntries>,
+    mut settings: Settings,
+    mut navigation: MenuNavigation,
+) {
+    if settings.rebinding.is_capturing() {
+        return;
+    }
+
+    for (interaction, button) in q_buttons.iter() {
+        let Some(index) = entries.entries.iter().position(|entry| *entry == button.0) else {
+            continue;
+        };
+
+        match interaction {
+            Interaction::Hovered => entries.selected = index,
+            Interaction::Pressed => {
+                entries.selected = index;
+                navigation.activate(button.0, &mut settings);
+            },
+            Interaction::None => {},
+        }
+    }
+}
+
+fn update_menu_labels(
+    mut q_buttons: Query<(&MenuButton, &Children, &mut BackgroundColor)>,
+    mut q_text: Query<&mut Text>,
+    entries: Res<MenuEntries>,
+    settings: Settings,
+) {
+    let selected = entries.selected();
+
+    for (button, children, mut background) in q_buttons.iter_mut() {
+        let highlight = if Some(button.0) == selected {
+            Color::from(tailwind::SLATE_700)
+        } else {
+            Color::NONE
+        };
+        if background.0 != highlight {
+            background.0 = highlight;
+        }
+
+        let label = button.0.label(&settings);
+        for &child in children.iter() {
+            if let Ok(mut text) = q_text.get_mut(child) {
+                if **text != label {
+                    **text = label.clone();
+                }
+            }
+        }
+    }
+}
@@ -1,9 +1,18 @@
+use crate::{
+    global_input::GlobalAction,
+    playfield::Boundary,
+    state::PlayingGame,
+};
 use bevy::prelude::*;
+use leafwing_input_manager::prelude::ActionState;
 
 pub struct OrientationPlugin;
 
 impl Plugin for OrientationPlugin {
-    fn build(&self, app: &mut App) { app.init_resource::<CameraOrientation>(); }
+    fn build(&self, app: &mut App) {
+        app.init_resource::<CameraOrientation>()
+            .add_systems(Update, cycle_orientation.run_if(in_state(PlayingGame)));
+    }
 }
 
 // centralize orientation defaults for a quick change-up
@@ -34,6 +43,16 @@ pub enum OrientationType {
     BehindSpaceship3D,
 }
 
+impl OrientationType {
+    pub fn next(self) -> Self {
+        match self {
+            Self::BehindSpaceship => Self::BehindSpaceship3D,
+            Self::BehindSpaceship3D => Self::TopDown,
+            Self::TopDown => Self::BehindSpaceship,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Reflect)]
 pub struct OrientationConfig {
     pub allow_3d:         bool,
@@ -87,6 +106,15 @@ impl CameraOrientation {
             },
         };
     }
+
+    // the camera's home sits out along axis_profundus at twice the depth of the
+    // boundary so the whole playfield is in view whichever way we're facing
+    pub fn locus(&self, boundary_scale: Vec3) -> Transform {
+        let depth = (boundary_scale * self.config.axis_profundus).length();
+
+        Transform::from_translation(self.config.nexus + self.config.axis_profundus * depth * 2.)
+            .looking_at(self.config.nexus, self.config.axis_mundi)
+    }
 }
 
 impl Default for CameraOrientation {
@@ -99,3 +127,34 @@ impl Default for CameraOrientation {
         mode
     }
 }
+
+// set_orientation starts over from the defaults so the locus has to be worked
+// out again for the new orientation - the camera, lights, planes and spaceship
+// controls all pick up the change from the resource
+fn cycle_orientation(
+    mut orientation: ResMut<CameraOrientation>,
+    boundary: Res<Boundary>,
+    user_input: Res<ActionState<GlobalAction>>,
+) {
+    if !user_input.just_pressed(&GlobalAction::CycleOrientation) {
+        return;
+    }
+
+    let next_orientation = orientation.orientation.next();
+    orientation.set_orientation(next_orientation);
+    orientation.config.locus = orientation.locus(boundary.scale());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn next_cycles_through_every_orientation() {
+        let start = OrientationType::TopDown;
+
+        assert_eq!(start.next(), OrientationType::BehindSpaceship);
+        assert_eq!(start.next().next(), OrientationType::BehindSpaceship3D);
+        assert_eq!(start.next().next().next(), start);
+    }
+}
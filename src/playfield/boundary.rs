@@ -437,6 +437,30 @@ impl Boundary {
         None
     }
 
+    /// the shortest vector from `from` to `to` - because actors teleport from
+    /// one side of the boundary to the other, going "the other way" across
+    /// the wrap is sometimes shorter than going directly
+    pub fn wrapped_delta(&self, from: Vec3, to: Vec3) -> Vec3 {
+        let size = self.transform.scale;
+        let mut delta = to - from;
+
+        for axis in 0..3 {
+            if size[axis] > 0.0 {
+                delta[axis] -= size[axis] * (delta[axis] / size[axis]).round();
+            }
+        }
+
+        delta
+    }
+
+    /// wrapped_delta to whichever of `targets` is closest, if there are any
+    pub fn nearest_wrapped_delta(&self, from: Vec3, targets: impl IntoIterator<Item = Vec3>) -> Option<Vec3> {
+        targets
+            .into_iter()
+            .map(|target| self.wrapped_delta(from, target))
+            .min_by(|a, b| a.length_squared().total_cmp(&b.length_squared()))
+    }
+
     pub fn longest_diagonal(&self) -> f32 {
         let boundary_scale = self.scale();
         (boundary_scale.x.powi(2) + boundary_scale.y.powi(2) + boundary_scale.z.powi(2)).sqrt()
@@ -527,3 +551,49 @@ fn intersect_circle_with_line_segment(portal: &Portal, start: Vec3, end: Vec3) -
 
     intersections
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn boundary() -> Boundary {
+        Boundary {
+            transform: Transform::from_scale(Vec3::new(100., 50., 50.)),
+            ..default()
+        }
+    }
+
+    #[test]
+    fn wrapped_delta_goes_directly_when_that_is_shorter() {
+        let delta = boundary().wrapped_delta(Vec3::new(-10., 0., 0.), Vec3::new(20., 5., 0.));
+
+        assert_eq!(delta, Vec3::new(30., 5., 0.));
+    }
+
+    #[test]
+    fn wrapped_delta_goes_across_the_wrap_when_that_is_shorter() {
+        let delta = boundary().wrapped_delta(Vec3::new(-45., 0., 0.), Vec3::new(45., 0., 0.));
+
+        assert_eq!(delta, Vec3::new(-10., 0., 0.));
+    }
+
+    #[test]
+    fn wrapped_delta_wraps_each_axis_on_its_own() {
+        let delta = boundary().wrapped_delta(Vec3::new(0., -20., 20.), Vec3::new(10., 20., -20.));
+
+        assert_eq!(delta, Vec3::new(10., -10., 10.));
+    }
+
+    #[test]
+    fn nearest_wrapped_delta_picks_the_closest_across_the_wrap() {
+        let targets = [Vec3::new(20., 0., 0.), Vec3::new(45., 0., 0.)];
+        let delta = boundary().nearest_wrapped_delta(Vec3::new(-45., 0., 0.), targets);
+
+        assert_eq!(delta, Some(Vec3::new(-10., 0., 0.)));
+    }
+
+    #[test]
+    fn nearest_wrapped_delta_without_targets_is_none() {
+        assert_eq!(boundary().nearest_wrapped_delta(Vec3::ZERO, []), None);
+    }
+}
@@ -5,7 +5,10 @@ mod portals;
 
 pub use crate::playfield::{
     boundary::Boundary,
-    portals::ActorPortals,
+    portals::{
+        ActorPortals,
+        TransientPortal,
+    },
 };
 
 use crate::playfield::{
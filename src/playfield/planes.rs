@@ -156,7 +156,7 @@ fn manage_box_planes(
     planes_config: Res<PlaneConfig>,
     planes: Query<(Entity, &BoxPlane)>,
 ) {
-    if !planes_config.is_changed() {
+    if !planes_config.is_changed() && !orientation.is_changed() {
         return;
     }
 
@@ -186,54 +186,27 @@ fn manage_box_planes(
     }
 }
 
+// front and back are along axis_profundus, top and bottom along axis_mundi and
+// left and right along axis_orbis so the faces follow the orientation around
 fn get_plane_specifications(
     config: &Res<PlaneConfig>,
     box_size: Vec3,
     orientation: &OrientationConfig,
 ) -> [(PlaneType, bool, Vec3, Vec3, Vec3); 6] {
+    let face = |plane_type: PlaneType, enabled: bool, axis: Vec3| {
+        let along = axis.abs();
+        let size = box_size * (Vec3::ONE - along) + along * config.thickness;
+        let position = axis * box_size / 2.;
+        (plane_type, enabled, size, position, axis)
+    };
+
     [
-        (
-            PlaneType::Back,
-            config.back,
-            Vec3::new(box_size.x, box_size.y, config.thickness),
-            Vec3::new(0., 0., -box_size.z / 2.),
-            orientation.axis_profundus,
-        ),
-        (
-            PlaneType::Front,
-            config.front,
-            Vec3::new(box_size.x, box_size.y, config.thickness),
-            Vec3::new(0., 0., box_size.z / 2.),
-            orientation.axis_profundus,
-        ),
-        (
-            PlaneType::Bottom,
-            config.bottom,
-            Vec3::new(box_size.x, config.thickness, box_size.z),
-            Vec3::new(0., -box_size.y / 2., 0.0),
-            orientation.axis_mundi,
-        ),
-        (
-            PlaneType::Top,
-            config.top,
-            Vec3::new(box_size.x, config.thickness, box_size.z),
-            Vec3::new(0., box_size.y / 2., 0.0),
-            orientation.axis_mundi,
-        ),
-        (
-            PlaneType::Left,
-            config.left,
-            Vec3::new(config.thickness, box_size.y, box_size.z),
-            Vec3::new(-box_size.x / 2., 0., 0.0),
-            orientation.axis_orbis,
-        ),
-        (
-            PlaneType::Right,
-            config.right,
-            Vec3::new(config.thickness, box_size.y, box_size.z),
-            Vec3::new(box_size.x / 2., 0., 0.0),
-            orientation.axis_orbis,
-        ),
+        face(PlaneType::Back, config.back, -orientation.axis_profundus),
+        face(PlaneType::Front, config.front, orientation.axis_profundus),
+        face(PlaneType::Bottom, config.bottom, -orientation.axis_mundi),
+        face(PlaneType::Top, config.top, orientation.axis_mundi),
+        face(PlaneType::Left, config.left, -orientation.axis_orbis),
+        face(PlaneType::Right, config.right, orientation.axis_orbis),
     ]
 }
 
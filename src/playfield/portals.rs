@@ -3,6 +3,7 @@ use crate::{
         Aabb,
         Teleporter,
     },
+    despawn::despawn,
     global_input::{
         toggle_active,
         GlobalAction,
@@ -47,6 +48,8 @@ impl Plugin for PortalPlugin {
                 ResourceInspectorPlugin::<PortalConfig>::default()
                     .run_if(toggle_active(false, GlobalAction::PortalInspector)),
             )
+            // transient portals have no health so they'd outlive the run
+            .add_systems(OnExit(PlayingGame), despawn_transient_portals)
             .add_systems(
                 Update,
                 (
@@ -54,6 +57,7 @@ impl Plugin for PortalPlugin {
                     init_portals,
                     draw_approaching_portals,
                     draw_emerging_portals,
+                    draw_transient_portals,
                 )
                     .run_if(in_state(PlayingGame)),
             );
@@ -153,6 +157,33 @@ impl Default for Portal {
     }
 }
 
+/// a portal that isn't tied to an actor approaching or emerging from a wall -
+/// it opens at full radius wherever it's spawned and shrinks away over
+/// `duration` seconds before despawning itself, e.g., the spaceship jumping
+/// through hyperspace
+#[derive(Component, Clone, Debug)]
+pub struct TransientPortal {
+    color:          Color,
+    duration:       f32,
+    initial_radius: f32,
+    portal:         Portal,
+}
+
+impl TransientPortal {
+    pub fn new(position: Vec3, radius: f32, duration: f32, color: Color) -> Self {
+        Self {
+            color,
+            duration,
+            initial_radius: radius,
+            portal: Portal {
+                position,
+                radius,
+                ..default()
+            },
+        }
+    }
+}
+
 fn init_portals(
     mut q_actor: Query<(&Aabb, &Transform, &Velocity, &Teleporter, &mut ActorPortals)>,
     boundary: Res<Boundary>,
@@ -399,3 +430,44 @@ fn draw_emerging_portals(
         }
     }
 }
+
+fn draw_transient_portals(
+    mut commands: Commands,
+    time: Res<Time>,
+    boundary: Res<Boundary>,
+    config: Res<PortalConfig>,
+    orientation: Res<CameraOrientation>,
+    mut q_portals: Query<(Entity, &mut TransientPortal)>,
+    mut gizmos: Gizmos<PortalGizmo>,
+) {
+    // transient portals always face the camera's home position
+    let normal = Dir3::new(orientation.config.axis_profundus).unwrap_or(Dir3::Z);
+
+    for (entity, mut transient) in q_portals.iter_mut() {
+        let started = *transient.portal.fade_out_started.get_or_insert(time.elapsed_secs());
+        let elapsed_time = time.elapsed_secs() - started;
+        let progress = (elapsed_time / transient.duration.max(f32::EPSILON)).clamp(0.0, 1.0);
+
+        transient.portal.normal = normal;
+        transient.portal.radius = transient.initial_radius * (1.0 - progress);
+
+        if progress >= 1.0 || transient.portal.radius < config.minimum_radius {
+            commands.entity(entity).despawn();
+            continue;
+        }
+
+        boundary.draw_portal(
+            &mut gizmos,
+            &transient.portal,
+            transient.color,
+            config.resolution,
+            &orientation,
+        );
+    }
+}
+
+fn despawn_transient_portals(mut commands: Commands, q_portals: Query<Entity, With<TransientPortal>>) {
+    for entity in q_portals.iter() {
+        despawn(&mut commands, entity);
+    }
+}
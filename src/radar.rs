@@ -0,0 +1,368 @@
+use crate::{
+    actor::{
+        player::Player,
+        ActorKind,
+        Spaceship,
+    },
+    camera::{
+        PrimaryCamera,
+        RenderLayer,
+    },
+    global_input::{
+        toggle_active,
+        GlobalAction,
+    },
+    playfield::Boundary,
+    state::PlayingGame,
+};
+use bevy::{
+    color::palettes::tailwind,
+    prelude::*,
+    render::view::RenderLayers,
+};
+use bevy_inspector_egui::{
+    inspector_options::std_options::NumberDisplay,
+    prelude::*,
+    quick::ResourceInspectorPlugin,
+};
+
+const RADAR_MARGIN: f32 = 10.;
+
+pub struct RadarPlugin;
+
+impl Plugin for RadarPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<RadarConfig>()
+            .init_resource::<RadarConfig>()
+            .add_plugins(
+                ResourceInspectorPlugin::<RadarConfig>::default()
+                    .run_if(toggle_active(false, GlobalAction::RadarInspector)),
+            )
+            .add_systems(Startup, spawn_radar)
+            .add_systems(OnEnter(PlayingGame), show_radar)
+            .add_systems(OnExit(PlayingGame), hide_radar)
+            .add_systems(
+                Update,
+                (resize_radar, update_radar_blips, update_threat_arrows).run_if(in_state(PlayingGame)),
+            );
+    }
+}
+
+// the radar is a top down map of the boundary - x across and y up, the same
+// plane the spaceships fly in - with a dot for every actor. threats outside
+// the view get an arrow at the edge of the screen, limited to the max_arrows
+// closest so a full wave doesn't ring the screen with them
+#[derive(Resource, Reflect, InspectorOptions, Debug, Clone)]
+#[reflect(Resource, InspectorOptions)]
+pub struct RadarConfig {
+    pub arrows_enabled:       bool,
+    #[inspector(min = 8.0, max = 64.0, display = NumberDisplay::Slider)]
+    pub arrow_margin:         f32,
+    #[inspector(min = 8.0, max = 64.0, display = NumberDisplay::Slider)]
+    pub arrow_size:           f32,
+    pub background_color:     Color,
+    #[inspector(min = 1.0, max = 12.0, display = NumberDisplay::Slider)]
+    pub blip_size:            f32,
+    #[inspector(min = 0, max = 32, display = NumberDisplay::Slider)]
+    pub max_arrows:           usize,
+    pub missile_color:        Color,
+    pub nateroid_color:       Color,
+    pub planet_color:         Color,
+    pub radar_enabled:        bool,
+    #[inspector(min = 100.0, max = 600.0, display = NumberDisplay::Slider)]
+    pub radar_width:          f32,
+    pub saucer_color:         Color,
+    pub saucer_missile_color: Color,
+    pub spaceship_color:      Color,
+}
+
+impl Default for RadarConfig {
+    fn default() -> Self {
+        Self {
+            arrows_enabled:       true,
+            arrow_margin:         24.,
+            arrow_size:           28.,
+            background_color:     Color::from(tailwind::SLATE_900).with_alpha(0.6),
+            blip_size:            4.,
+            max_arrows:           8,
+            missile_color:        Color::from(tailwind::SKY_300),
+            nateroid_color:       Color::from(tailwind::ORANGE_400),
+            planet_color:         Color::from(tailwind::BLUE_500),
+            radar_enabled:        true,
+            radar_width:          240.,
+            saucer_color:         Color::from(tailwind::RED_500),
+            saucer_missile_color: Color::from(tailwind::RED_300),
+            spaceship_color:      Color::from(tailwind::GREEN_400),
+        }
+    }
+}
+
+impl RadarConfig {
+    pub fn color(&self, kind: ActorKind) -> Color {
+        match kind {
+            ActorKind::Missile => self.missile_color,
+            ActorKind::Nateroid => self.nateroid_color,
+            ActorKind::Planet => self.planet_color,
+            ActorKind::Saucer => self.saucer_color,
+            ActorKind::SaucerMissile => self.saucer_missile_color,
+            ActorKind::Spaceship => self.spaceship_color,
+        }
+    }
+}
+
+// anything that can hurt a spaceship without a player behind it
+fn is_threat(kind: ActorKind) -> bool {
+    matches!(kind, ActorKind::Nateroid | ActorKind::Saucer | ActorKind::SaucerMissile)
+}
+
+#[derive(Component)]
+struct Radar;
+
+#[derive(Component)]
+struct RadarScreen;
+
+#[derive(Component)]
+struct RadarBlip;
+
+#[derive(Component)]
+struct ThreatArrow;
+
+fn spawn_radar(mut commands: Commands, config: Res<RadarConfig>) {
+    commands
+        .spawn((
+            Radar,
+            Node {
+                width: Val::Percent(100.),
+                height: Val::Percent(100.),
+                position_type: PositionType::Absolute,
+                ..default()
+            },
+            Visibility::Hidden,
+            RenderLayers::from_layers(RenderLayer::Game.layers()),
+        ))
+        .with_children(|radar| {
+            radar.spawn((
+                RadarScreen,
+                Node {
+                    width: Val::Px(config.radar_width),
+                    height: Val::Px(config.radar_width / 2.),
+                    position_type: PositionType::Absolute,
+                    right: Val::Px(RADAR_MARGIN),
+                    bottom: Val::Px(RADAR_MARGIN),
+                    border: UiRect::all(Val::Px(1.)),
+                    ..default()
+                },
+                BackgroundColor(config.background_color),
+                BorderColor(Color::from(tailwind::SLATE_500)),
+            ));
+        });
+}
+
+fn show_radar(mut q_radar: Query<&mut Visibility, With<Radar>>) {
+    for mut visibility in q_radar.iter_mut() {
+        *visibility = Visibility::Inherited;
+    }
+}
+
+fn hide_radar(mut q_radar: Query<&mut Visibility, With<Radar>>) {
+    for mut visibility in q_radar.iter_mut() {
+        *visibility = Visibility::Hidden;
+    }
+}
+
+// the screen keeps the boundary's aspect ratio so the map isn't stretched
+fn resize_radar(
+    mut q_screen: Query<(&mut Node, &mut BackgroundColor, &mut Visibility), With<RadarScreen>>,
+    boundary: Res<Boundary>,
+    config: Res<RadarConfig>,
+) {
+    if !boundary.is_changed() && !config.is_changed() {
+        return;
+    }
+
+    let size = boundary.transform.scale;
+    let aspect = if size.x > 0.0 { size.y / size.x } else { 1.0 };
+
+    for (mut node, mut background, mut visibility) in q_screen.iter_mut() {
+        node.width = Val::Px(config.radar_width);
+        node.height = Val::Px(config.radar_width * aspect);
+        background.0 = config.background_color;
+        *visibility = if config.radar_enabled {
+            Visibility::Inherited
+        } else {
+            Visibility::Hidden
+        };
+    }
+}
+
+// where in the boundary the position is from 0 to 1 on each axis
+fn boundary_fraction(boundary: &Boundary, position: Vec3) -> Vec2 {
+    let size = boundary.transform.scale.max(Vec3::splat(f32::EPSILON));
+    let fraction = (position - boundary.transform.translation) / size + Vec3::splat(0.5);
+
+    fraction.truncate().clamp(Vec2::ZERO, Vec2::ONE)
+}
+
+// blips are pooled - we reuse whatever is already there, spawn more when
+// there are more actors than blips and hide the leftovers
+fn update_radar_blips(
+    mut commands: Commands,
+    q_actors: Query<(&ActorKind, &Transform)>,
+    q_screen: Query<Entity, With<RadarScreen>>,
+    mut q_blips: Query<(&mut Node, &mut BackgroundColor, &mut Visibility), With<RadarBlip>>,
+    boundary: Res<Boundary>,
+    config: Res<RadarConfig>,
+) {
+    let Ok(screen) = q_screen.get_single() else {
+        return;
+    };
+
+    let size = boundary.transform.scale;
+    let width = config.radar_width;
+    let height = if size.x > 0.0 { width * size.y / size.x } else { width };
+    let half_blip = config.blip_size / 2.;
+
+    let mut actors = q_actors.iter();
+
+    for (mut node, mut background, mut visibility) in q_blips.iter_mut() {
+        let Some((kind, transform)) = actors.next() else {
+            *visibility = Visibility::Hidden;
+            continue;
+        };
+
+        let fraction = boundary_fraction(&boundary, transform.translation);
+
+        node.left = Val::Px(fraction.x * width - half_blip);
+        node.top = Val::Px((1.0 - fraction.y) * height - half_blip);
+        node.width = Val::Px(config.blip_size);
+        node.height = Val::Px(config.blip_size);
+        background.0 = config.color(*kind);
+        *visibility = Visibility::Inherited;
+    }
+
+    for (kind, transform) in actors {
+        let fraction = boundary_fraction(&boundary, transform.translation);
+
+        let blip = commands
+            .spawn((
+                RadarBlip,
+                Node {
+                    width: Val::Px(config.blip_size),
+                    height: Val::Px(config.blip_size),
+                    position_type: PositionType::Absolute,
+                    left: Val::Px(fraction.x * width - half_blip),
+                    top: Val::Px((1.0 - fraction.y) * height - half_blip),
+                    ..default()
+                },
+                BackgroundColor(config.color(*kind)),
+                BorderRadius::MAX,
+            ))
+            .id();
+
+        commands.entity(screen).add_child(blip);
+    }
+}
+
+// threats are measured from the lowest numbered spaceship still flying across
+// the shortest path through the wrap - so a nateroid just over the boundary
+// behind us points behind us rather than all the way across the playfield.
+// without a spaceship we measure from the center of the boundary
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
+fn update_threat_arrows(
+    mut commands: Commands,
+    q_actors: Query<(&ActorKind, &Transform), Without<ThreatArrow>>,
+    q_spaceships: Query<(&Transform, &Player), (With<Spaceship>, Without<ThreatArrow>)>,
+    q_camera: Query<(&Camera, &GlobalTransform), With<PrimaryCamera>>,
+    q_radar: Query<Entity, With<Radar>>,
+    mut q_arrows: Query<(&mut Node, &mut Transform, &mut TextColor, &mut Visibility), With<ThreatArrow>>,
+    boundary: Res<Boundary>,
+    config: Res<RadarConfig>,
+) {
+    let (Ok((camera, camera_transform)), Ok(radar)) = (q_camera.get_single(), q_radar.get_single()) else {
+        return;
+    };
+
+    let Some(viewport_size) = camera.logical_viewport_size() else {
+        return;
+    };
+
+    let origin = q_spaceships
+        .iter()
+        .min_by_key(|(_, player)| player.0)
+        .map_or(boundary.transform.translation, |(transform, _)| transform.translation);
+
+    // whether it's on screen is about where it's actually drawn - the wrapped
+    // position is only for pointing the arrow along the shortest way there
+    let mut threats: Vec<(ActorKind, Vec3)> = q_actors
+        .iter()
+        .filter(|(kind, _)| config.arrows_enabled && is_threat(**kind))
+        .filter(|(_, transform)| {
+            !camera
+                .world_to_viewport(camera_transform, transform.translation)
+                .is_ok_and(|point| point.cmpge(Vec2::ZERO).all() && point.cmple(viewport_size).all())
+        })
+        .map(|(kind, transform)| (*kind, origin + boundary.wrapped_delta(origin, transform.translation)))
+        .collect();
+
+    threats.sort_by(|(_, a), (_, b)| a.distance_squared(origin).total_cmp(&b.distance_squared(origin)));
+    threats.truncate(config.max_arrows);
+
+    let view = camera_transform.affine().inverse();
+    let half_viewport = viewport_size / 2.;
+    let inset = (half_viewport - Vec2::splat(config.arrow_margin)).max(Vec2::ONE);
+
+    // in camera space x is right and y is up whether the threat is in front
+    // of us or behind, so it tells us which edge to put the arrow on
+    let mut placements = threats.into_iter().filter_map(|(kind, position)| {
+        let direction = view.transform_point3(position).truncate().try_normalize()?;
+        let reach = (inset.x / direction.x.abs()).min(inset.y / direction.y.abs());
+        let screen = half_viewport + Vec2::new(direction.x, -direction.y) * reach;
+
+        // ui is y down so turning counterclockwise on screen is a negative
+        // rotation
+        let rotation = Quat::from_rotation_z(-direction.y.atan2(direction.x));
+
+        Some((config.color(kind), screen, rotation))
+    });
+
+    let half_arrow = config.arrow_size / 2.;
+
+    for (mut node, mut transform, mut text_color, mut visibility) in q_arrows.iter_mut() {
+        let Some((color, screen, rotation)) = placements.next() else {
+            *visibility = Visibility::Hidden;
+            continue;
+        };
+
+        node.left = Val::Px(screen.x - half_arrow);
+        node.top = Val::Px(screen.y - half_arrow);
+        transform.rotation = rotation;
+        text_color.0 = color;
+        *visibility = Visibility::Inherited;
+    }
+
+    for (color, screen, rotation) in placements {
+        let arrow = commands
+            .spawn((
+                ThreatArrow,
+                Text::new(">"),
+                TextFont {
+                    font_size: config.arrow_size,
+                    ..default()
+                },
+                TextColor(color),
+                TextLayout::new_with_justify(JustifyText::Center),
+                Node {
+                    width: Val::Px(config.arrow_size),
+                    height: Val::Px(config.arrow_size),
+                    position_type: PositionType::Absolute,
+                    left: Val::Px(screen.x - half_arrow),
+                    top: Val::Px(screen.y - half_arrow),
+                    ..default()
+                },
+                Transform::from_rotation(rotation),
+            ))
+            .id();
+
+        commands.entity(radar).add_child(arrow);
+    }
+}
@@ -0,0 +1,238 @@
+use crate::{
+    actor::{
+        nateroid::Nateroid,
+        player::{
+            Player,
+            PlayerConfig,
+        },
+        ActorKind,
+        Boss,
+        KillEvent,
+    },
+    global_input::{
+        toggle_active,
+        GlobalAction,
+    },
+    schedule::InGameSet,
+    state::{
+        GameState,
+        PlayingGame,
+    },
+};
+use bevy::prelude::*;
+use bevy_inspector_egui::{
+    inspector_options::std_options::NumberDisplay,
+    prelude::*,
+    quick::ResourceInspectorPlugin,
+};
+
+pub struct ScorePlugin;
+
+impl Plugin for ScorePlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<ScoreConfig>()
+            .init_resource::<ScoreConfig>()
+            .init_resource::<Score>()
+            .add_plugins(
+                ResourceInspectorPlugin::<ScoreConfig>::default()
+                    .run_if(toggle_active(false, GlobalAction::ScoreInspector)),
+            )
+            // a session starts whenever we (re)enter the game
+            .add_systems(OnEnter(PlayingGame), reset_score)
+            .add_systems(OnEnter(GameState::GameOver), report_score)
+            // victims of a kill are still alive until the DespawnEntities flush
+            // so we can look at their size and age right up until then
+            .add_systems(Update, award_points.in_set(InGameSet::DespawnEntities));
+    }
+}
+
+// a saucer kill is always worth saucer_points and a boss kill is always worth
+// boss_points - a boss is a nateroid but its size would make it worth next to
+// nothing otherwise
+//
+// reference_scalar is the nateroid size that's worth exactly base_points -
+// smaller nateroids are harder to hit so they're worth proportionally more
+//
+// quick_kill_bonus is awarded in full for a nateroid killed the instant it
+// appears and shrinks to nothing by the time it has lived quick_kill_window
+// seconds
+#[derive(Resource, Reflect, InspectorOptions, Debug, PartialEq, Clone, Copy)]
+#[reflect(Resource, InspectorOptions)]
+pub struct ScoreConfig {
+    #[inspector(min = 1.0, max = 1000.0, display = NumberDisplay::Slider)]
+    pub base_points:       f32,
+    #[inspector(min = 0.0, max = 10000.0, display = NumberDisplay::Slider)]
+    pub boss_points:       f32,
+    #[inspector(min = 0.0, max = 1000.0, display = NumberDisplay::Slider)]
+    pub quick_kill_bonus:  f32,
+    #[inspector(min = 1.0, max = 60.0, display = NumberDisplay::Slider)]
+    pub quick_kill_window: f32,
+    #[inspector(min = 0.1, max = 10.0, display = NumberDisplay::Slider)]
+    pub reference_scalar:  f32,
+    #[inspector(min = 0.0, max = 5000.0, display = NumberDisplay::Slider)]
+    pub saucer_points:     f32,
+}
+
+impl Default for ScoreConfig {
+    fn default() -> Self {
+        Self {
+            base_points:       100.,
+            boss_points:       2500.,
+            quick_kill_bonus:  50.,
+            quick_kill_window: 10.,
+            reference_scalar:  1.,
+            saucer_points:     500.,
+        }
+    }
+}
+
+impl ScoreConfig {
+    pub fn points_for(&self, scalar: f32, lifetime: f32) -> u32 {
+        let size_multiplier = self.reference_scalar / scalar.max(f32::EPSILON);
+        let quickness = (1.0 - lifetime / self.quick_kill_window).clamp(0.0, 1.0);
+
+        (self.base_points * size_multiplier + self.quick_kill_bonus * quickness).round() as u32
+    }
+}
+
+/// one line in the per-session record of how points were earned so playtest
+/// runs can be compared after the fact
+#[derive(Debug, Clone)]
+pub struct ScoreEntry {
+    pub awarded_at: f32,
+    pub lifetime:   f32,
+    pub player:     Option<Player>,
+    pub points:     u32,
+    pub scalar:     f32,
+}
+
+/// total is everyone's points together - player_totals is indexed by Player
+#[derive(Resource, Debug, Default)]
+pub struct Score {
+    pub total:         u32,
+    pub player_totals: Vec<u32>,
+    pub history:       Vec<ScoreEntry>,
+}
+
+impl Score {
+    fn award(&mut self, entry: ScoreEntry) {
+        self.total += entry.points;
+
+        if let Some(player_total) = entry.player.and_then(|player| self.player_totals.get_mut(player.0)) {
+            *player_total += entry.points;
+        }
+
+        self.history.push(entry);
+    }
+}
+
+fn reset_score(mut score: ResMut<Score>, player_config: Res<PlayerConfig>) {
+    *score = Score {
+        player_totals: vec![0; player_config.player_count()],
+        ..default()
+    };
+}
+
+fn award_points(
+    mut kill_events: EventReader<KillEvent>,
+    q_nateroid: Query<(&Transform, &Nateroid, Has<Boss>)>,
+    q_transform: Query<&Transform>,
+    config: Res<ScoreConfig>,
+    mut score: ResMut<Score>,
+    time: Res<Time>,
+) {
+    let now = time.elapsed_secs();
+
+    for kill in kill_events.read() {
+        if kill.killer_kind != ActorKind::Missile {
+            continue;
+        }
+
+        match kill.victim_kind {
+            ActorKind::Nateroid => {
+                let Ok((transform, nateroid, is_boss)) = q_nateroid.get(kill.victim) else {
+                    continue;
+                };
+
+                let scalar = transform.scale.max_element();
+                let lifetime = nateroid.age(now);
+
+                let points = if is_boss {
+                    config.boss_points.round() as u32
+                } else {
+                    config.points_for(scalar, lifetime)
+                };
+
+                score.award(ScoreEntry {
+                    awarded_at: now,
+                    lifetime,
+                    player: kill.killer_player,
+                    points,
+                    scalar,
+                });
+            },
+            ActorKind::Saucer => {
+                let scalar = q_transform
+                    .get(kill.victim)
+                    .map_or(1.0, |transform| transform.scale.max_element());
+
+                score.award(ScoreEntry {
+                    awarded_at: now,
+                    lifetime: 0.0,
+                    player: kill.killer_player,
+                    points: config.saucer_points.round() as u32,
+                    scalar,
+                });
+            },
+            _ => {},
+        }
+    }
+}
+
+fn report_score(score: Res<Score>) {
+    println!("final score: {} from {} kills", score.total, score.history.len());
+    if score.player_totals.len() > 1 {
+        for (index, player_total) in score.player_totals.iter().enumerate() {
+            println!("  player {}: {} points", index + 1, player_total);
+        }
+    }
+    for entry in score.history.iter() {
+        println!(
+            "  {:>8.2}s: {:>5} points (scalar {:.2}, lived {:.2}s)",
+            entry.awarded_at, entry.points, entry.scalar, entry.lifetime
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config() -> ScoreConfig {
+        ScoreConfig {
+            base_points:       100.,
+            quick_kill_bonus:  50.,
+            quick_kill_window: 10.,
+            reference_scalar:  1.,
+            ..default()
+        }
+    }
+
+    #[test]
+    fn reference_size_is_worth_base_points_once_the_window_closes() {
+        assert_eq!(config().points_for(1., 10.), 100);
+        assert_eq!(config().points_for(1., 60.), 100);
+    }
+
+    #[test]
+    fn smaller_nateroids_are_worth_proportionally_more() {
+        assert_eq!(config().points_for(0.5, 10.), 200);
+        assert_eq!(config().points_for(2., 10.), 50);
+    }
+
+    #[test]
+    fn quick_kill_bonus_shrinks_over_the_window() {
+        assert_eq!(config().points_for(1., 0.), 150);
+        assert_eq!(config().points_for(1., 5.), 125);
+    }
+}
@@ -58,9 +58,6 @@ fn run_splash(
         text.font_size += 1.2;
     }
     if spawn_timer.timer.just_finished() {
-        next_state.set(GameState::InGame {
-            paused:     false,
-            inspecting: false,
-        });
+        next_state.set(GameState::MainMenu);
     }
 }
@@ -11,16 +11,13 @@ pub struct StatePlugin;
 impl Plugin for StatePlugin {
     fn build(&self, app: &mut App) {
         app.init_state::<GameState>()
+            .add_sub_state::<MenuScreen>()
             .add_computed_state::<PlayingGame>()
             .add_computed_state::<IsPaused>()
+            .add_sub_state::<PauseScreen>()
             .add_computed_state::<IsInspecting>()
-            .add_systems(
-                Update,
-                (
-                    toggle_pause.run_if(in_state(PlayingGame)),
-                    transition_to_in_game.run_if(in_state(GameState::GameOver)),
-                ),
-            )
+            .add_systems(Update, toggle_pause.run_if(in_state(PlayingGame)))
+            .add_systems(Update, restart_game.run_if(in_state(GameState::Restarting)))
             .add_systems(OnEnter(IsPaused::Paused), pause_rapier)
             .add_systems(OnEnter(IsPaused::NotPaused), unpause_rapier)
             .add_systems(Update, log_transitions::<GameState>);
@@ -39,11 +36,27 @@ impl Plugin for StatePlugin {
 pub enum GameState {
     #[default]
     Splash,
+    MainMenu,
     InGame {
         paused:     bool,
         inspecting: bool,
     },
     GameOver,
+    // passes straight through to InGame - leaving PlayingGame and coming back
+    // is what tears down the old run and sets up a new one
+    Restarting,
+}
+
+// the screens within the main menu - it only exists while we're in MainMenu
+// and always opens on Main
+#[derive(SubStates, Debug, Clone, Copy, Default, Eq, PartialEq, Hash)]
+#[source(GameState = GameState::MainMenu)]
+pub enum MenuScreen {
+    Bindings,
+    Controls,
+    #[default]
+    Main,
+    Settings,
 }
 
 // as PlayingGame is a computed state that covers paused - we wanted it to have
@@ -94,6 +107,15 @@ impl ComputedStates for IsPaused {
     }
 }
 
+// the screens of the pause menu - only around while the game is paused
+#[derive(SubStates, Debug, Clone, Copy, Default, Eq, PartialEq, Hash)]
+#[source(IsPaused = IsPaused::Paused)]
+pub enum PauseScreen {
+    #[default]
+    Main,
+    Settings,
+}
+
 #[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
 pub enum IsInspecting {
     NotInspecting,
@@ -117,11 +139,18 @@ impl ComputedStates for IsInspecting {
     }
 }
 
+// escape on the pause menu's settings screen goes back to the pause menu
+// rather than resuming
 fn toggle_pause(
     user_input: Res<ActionState<GlobalAction>>,
     mut next_state: ResMut<NextState<GameState>>,
+    pause_screen: Option<Res<State<PauseScreen>>>,
     state: Res<State<GameState>>,
 ) {
+    if pause_screen.is_some_and(|screen| *screen.get() != PauseScreen::Main) {
+        return;
+    }
+
     if user_input.just_pressed(&GlobalAction::Pause) {
         if let GameState::InGame { paused, inspecting } = state.get() {
             next_state.set(GameState::InGame {
@@ -132,8 +161,7 @@ fn toggle_pause(
     }
 }
 
-fn transition_to_in_game(mut next_state: ResMut<NextState<GameState>>) {
-    println!("Transitioning to InGame");
+fn restart_game(mut next_state: ResMut<NextState<GameState>>) {
     next_state.set(GameState::InGame {
         paused:     false,
         inspecting: false,